@@ -0,0 +1,326 @@
+# Deutsch
+
+language = Sprache
+
+## Menu bar
+
+menu-add-locomotive = Lok/Wagen hinzufügen
+menu-add-order = Auftrag hinzufügen
+menu-paste-jobs = Aufträge einfügen
+menu-station-map = Stationskarte
+menu-trip = Fahrt
+menu-suggest-power = Traktion vorschlagen
+menu-job-board = Auftragstafel
+menu-checks = Prüfungen
+menu-checks-count = Prüfungen ({ $count })
+menu-undo = Rückgängig
+menu-redo = Wiederholen
+menu-shortcuts = Tastenkürzel
+
+## Common buttons
+
+button-add = Hinzufügen
+button-edit = Ändern
+button-cancel = Abbrechen
+
+## Locomotives panel
+
+locomotives-heading = Aktuelle Loks
+delete-locomotive = Lok entfernen
+add-loco-heading = Lok/Wagen hinzufügen
+add-loco-label = Lok/Wagen
+loco-quantity = Anzahl gleicher Fahrzeuge
+loco-details-weight = Gewicht: { $weight }
+loco-details-length = Länge: { $length }
+loco-unpowered = Bringt Gewicht, aber keine Zugkraft
+loco-conditions-pull = Zieht { $weight }
+loco-contribution = Anteil an der Zugkraft des Zuges unter diesen Bedingungen: { $percent } %
+# Same word in German.
+placement-label = Position
+placement-head = Zugspitze
+placement-after-cut = Hinter { $cut }
+placement-rear = Zugschluss
+loco-reversed = Rückwärts fahrend
+loco-details-speed = Höchstgeschwindigkeit: { $speed } km/h
+
+## Consist info panel
+
+info-heading = Zugdaten
+info-total-weight = - Gesamtgewicht: { $weight }
+info-still-to-pick-up = - Noch abzuholen: { $weight }
+info-supported-in-conditions = - Zulässig unter diesen Bedingungen: { $weight }
+info-supported-weights = Nennlasten
+# Only placeholders, the same in every language.
+info-supported-weight = { $rating }: { $weight }
+info-total-length = - Gesamtlänge: { $length }
+info-power-single = - Antrieb: alles an einer Stelle
+info-power-distributed = - Antrieb: verteilt auf { $positions } Stellen
+info-max-speed = - Höchstgeschwindigkeit: { $speed } km/h ({ $limiter })
+limiter-reversed = { $loco } rückwärts
+limiter-car-type = Wagen vom Typ { $car }
+limiter-hazmat = Gefahrgut in { $order }
+limiter-order = Vorgabe von { $order }
+rating-zero-grade = 0 % Steigung
+rating-two-grade = 2 % Steigung
+rating-two-grade-rain = 2 % Steigung bei Regen
+warning-no-power = Der Zug hat keine angetriebene Lok
+warning-overweight = Gesamtgewicht { $weight } überschreitet die zulässigen { $supported } bei { $rating }
+warning-stall = Bleibt an der Steigung von { $grade } % zwischen { $from } und { $to } liegen
+warning-slow-locomotive = Die { $loco } begrenzt den Zug auf { $speed } km/h
+warning-slow-locomotive-reversed = Die rückwärts fahrende { $loco } begrenzt den Zug auf { $speed } km/h
+
+## Conditions bar
+
+conditions-grade = Steigung:
+conditions-weather = Wetter
+conditions-sanding = Sanden
+conditions-coupler-limit = Kupplungsgrenze
+conditions-coupler-limit-hint = Begrenzt, was Traktion an einer Stelle bewegen kann, auf das Höchste, was eine Kupplung ziehen oder schieben kann. Dies ist eine Schätzung, kein Wert aus dem Spiel, daher standardmäßig aus; { $default } ist ein Ausgangswert. Über den Zug verteilte Traktion kann mehr bewegen.
+weather-dry = Trocken
+weather-rain = Regen
+weather-snow = Schnee
+weather-leaves = Laub auf den Schienen
+verdict-ok = ✔ Schafft es: { $weight } von { $supported } ({ $percent } %)
+verdict-too-heavy = ✖ Zu schwer: { $weight } von { $supported }, { $excess } zu viel
+verdict-no-power = ✖ Keine Zugkraft vorhanden
+
+## Consist checks
+
+checks-heading = Zugprüfung
+checks-passed = ✔ Keine Probleme gefunden
+checks-rules = Regeln
+severity-info = Hinweis
+severity-warning = Warnung
+severity-error = Fehler
+rule-caboose-last = Der Caboose muss am Zugende sein
+rule-hazmat-next-to-locomotive = Kein Gefahrgut neben einer Lok oder dem Caboose
+rule-hazmat-separation = Schutzwagen zwischen verschiedenen Gefahrgutklassen
+rule-slug-next-to-de6 = Ein DE6 Slug muss neben einer DE6 laufen
+rule-zero-weight-order = Kein Auftrag ohne Gewicht
+violation-caboose-last = Der Caboose ist nicht am Zugende
+violation-hazmat-next-to-locomotive = Gefahrgut-Wagengruppe { $order } läuft neben einer Lok oder dem Caboose
+violation-hazmat-separation = Zwischen den Gefahrgut-Wagengruppen { $order } und { $other } fehlen Schutzwagen
+violation-slug-next-to-de6 = Ein DE6 Slug ist nicht neben einer DE6 gekuppelt
+violation-zero-weight-order = Auftrag { $order } hat kein Gewicht
+checks-suggestion = Sichere Reihenfolge der Wagengruppen: { $cuts }
+checks-apply = Umordnen
+checks-no-safe-order = Keine Reihenfolge der Wagengruppen hält das Gefahrgut bei dieser Lokaufstellung sicher.
+hazmat-flammable = Entzündbar
+hazmat-explosive = Explosiv
+hazmat-gas = Gase
+
+## Suggest power
+
+power-heading = Traktion vorschlagen
+power-load = Schwerste Last auf der Route: { $weight }
+power-goal = Bevorzugen
+power-goal-fewest = Wenigste Loks
+power-goal-lightest = Leichteste
+power-goal-cheapest = Günstigste im Betrieb (geschätzt)
+power-none = Keine Kombination aus bis zu { $units } Loks zieht diese Last unter diesen Bedingungen.
+power-details = Zug { $weight } von { $supported } möglichen, Betrieb etwa ${ $cost } pro Stunde
+power-margin = { $weight } Reserve
+power-apply = Übernehmen
+power-roster = Lizenzen und eigene Loks
+power-licensed = Lizenz
+power-owned = Vorhanden:
+
+## Job board
+
+job-board-heading = Auftragstafel
+job-board-empty = Noch keine Aufträge auf der Tafel. Füge welche über „Aufträge einfügen“ hinzu.
+job-board-all-stations = Alle Stationen ({ $count })
+job-board-spare = Platz für weitere { $weight }
+job-board-spare-length = Platz für weitere { $weight } und { $length }
+job-board-license = Längenlizenz bis
+job-board-track = Kürzestes Gleis je Bahnhof
+job-board-goal = Auswählen nach
+job-board-goal-payout = Höchste Vergütung
+job-board-goal-cars = Meiste Wagen
+job-board-pick = Beste auswählen
+job-board-picked = Ausgewählt: { $weight }, { $length }, ${ $payout }
+job-board-too-much = Die ausgewählten Aufträge passen nicht zum Zug
+job-board-take = { $count } Aufträge übernehmen
+job-board-take-one = Übernehmen
+job-board-remove = Entfernen
+job-board-cars = Wagen
+job-board-payout = Vergütung
+
+## Orders table
+
+orders-heading = Aufträge
+orders-showing = { $shown } von { $total } Aufträgen: { $weight }, { $length }
+delivered-heading = Zugestellt ({ $count })
+column-order-name = Auftrag
+column-weight = Gewicht
+column-length = Länge
+column-pickup-station = Abholbahnhof
+column-pickup-track = Abholgleis
+column-dropoff-station = Zielbahnhof
+column-dropoff-track = Zielgleis
+# Same word in German.
+column-status = Status
+sort-hint = Zum Sortieren klicken
+order-menu-add = Auftrag hinzufügen...
+order-menu-above = Darüber
+order-menu-below = Darunter
+order-menu-move = Auftrag verschieben...
+order-menu-up = Nach oben
+order-menu-down = Nach unten
+order-menu-edit = Auftrag bearbeiten
+order-menu-to-board = Zurück auf die Auftragstafel
+order-menu-delete = Auftrag löschen
+status-planned = Geplant
+status-picked-up = Im Zug
+status-delivered = Zugestellt
+filter-search = Suche:
+filter-search-hint = Name, Auftrags-ID oder Gleis
+filter-pickup = Abholung:
+filter-dropoff = Ziel:
+filter-station = Von oder nach { $station }
+filter-clear = Filter zurücksetzen
+filter-any = Alle
+
+## Order form
+
+order-form-add-heading = Auftrag hinzufügen
+order-form-edit-heading = Auftrag bearbeiten
+order-form-name = Auftragsname
+order-form-job-id = Auftrags-ID
+order-form-weight = Gewicht ({ $unit })
+order-form-length = Länge ({ $unit })
+order-form-cars = Wagen
+order-form-car-type = Wagentyp
+order-form-car-type-unknown = Unbekannt
+order-form-cargo = Ladung
+order-form-hazmat = Gefahrgutklasse
+order-form-hazmat-none = Keine
+order-form-speed-limit = Geschwindigkeitsgrenze (km/h, optional)
+order-form-payout = Vergütung ($, optional)
+order-form-pickup-station = Abholbahnhof:
+order-form-pickup-track = Abholgleis
+order-form-dropoff-station = Zielbahnhof:
+order-form-dropoff-track = Zielgleis
+# Same word in German.
+order-form-status = Status
+error-required = Pflichtfeld
+error-out-of-range = Muss größer als 0 und höchstens { $max } { $unit } sein
+error-not-a-number = Keine Zahl
+error-track-format = Erwartet ein Gleis wie B7L
+error-car-count = Muss eine ganze Zahl bis { $max } sein
+error-speed-limit = Muss eine ganze Zahl bis { $max } km/h sein
+error-payout = Muss eine ganze Zahl bis { $max } Dollar sein
+error-same-track = Muss sich vom Abholgleis unterscheiden
+
+## Units
+
+units-mass = Gewichtseinheit
+units-length = Längeneinheit
+unit-tonnes = Metrische Tonnen
+unit-short-tons = US-Tonnen (short tons)
+unit-meters = Meter
+unit-feet = Fuß
+
+## Consist strip
+
+strip-pickup = Abholung: { $station } { $track }
+strip-dropoff = Abgabe: { $station } { $track }
+
+## Trip
+
+trip-heading = Fahrt
+trip-start-hint = Wähle den Bahnhof, an dem der Zug steht, um die Fahrt zu beginnen.
+trip-previous = Vorheriger Halt
+trip-next = Nächster Halt
+trip-not-started = Nicht begonnen
+trip-route = Strecke: { $stops }
+trip-set-out = Abstellen in { $station }
+trip-nothing-to-set-out = Nichts abzustellen.
+trip-set-out-order = - { $order } ({ $cars } Wagen) → { $track }
+trip-pick-up = Aufnehmen in { $station }
+trip-nothing-to-pick-up = Nichts aufzunehmen.
+trip-pick-up-order = - { $order } ({ $cars } Wagen) von { $track }
+trip-next-leg = Nächster Abschnitt: nach { $station }
+trip-last-leg = Nächster Abschnitt: keine weiteren Halte
+trip-weight = - Gewicht: { $weight }
+trip-length = - Länge: { $length }
+trip-advance = Bahnhof bedienen und weiterfahren
+trip-profile = Streckenprofil
+trip-profile-stalls = Bleibt hier liegen
+trip-profile-too-heavy = Zu schwer
+grades-provisional = Die Steigungen sind geschätzt, nicht aus dem Spiel gemessen.
+
+## Station map
+
+map-heading = Stationskarte
+map-reset = Ansicht zurücksetzen
+map-hint = Ziehen zum Verschieben, Scrollen zum Zoomen, Klick auf einen Bahnhof filtert die Aufträge.
+
+## Paste jobs
+
+paste-heading = Aufträge einfügen
+paste-hint = Füge Aufträge aus der Auftragsübersicht ein, einen pro Zeile oder einen Block pro Auftrag.
+paste-parse = Auslesen
+paste-add = { $count } Aufträge hinzufügen
+paste-add-to-board = Zur Auftragstafel hinzufügen
+paste-empty = Noch keine Aufträge ausgelesen.
+paste-pickup = Abholung
+paste-dropoff = Abgabe
+paste-track = Gleis
+paste-remove = Entfernen
+
+## Keyboard shortcuts
+
+shortcuts-heading = Tastenkürzel
+shortcuts-recording = Taste drücken… (Esc zum Abbrechen)
+shortcuts-set = Festlegen
+shortcuts-clear = Löschen
+shortcuts-reset = Auf Standard zurücksetzen
+action-add-order = Auftrag hinzufügen
+action-add-locomotive = Lok/Wagen hinzufügen
+action-paste-jobs = Aufträge einfügen
+action-edit-selected = Ausgewählten Auftrag bearbeiten
+action-delete-selected = Auswahl löschen
+action-move-up = Auswahl nach oben
+action-move-down = Auswahl nach unten
+action-select-previous = Vorherigen auswählen
+action-select-next = Nächsten auswählen
+action-toggle-power = Antrieb der ausgewählten Lok umschalten
+action-undo = Rückgängig
+action-redo = Wiederholen
+action-station-map = Stationskarte ein-/ausblenden
+action-trip = Fahrt ein-/ausblenden
+action-checks = Zugprüfungen ein-/ausblenden
+action-suggest-power = Traktion vorschlagen
+action-job-board = Auftragstafel ein-/ausblenden
+action-shortcuts = Tastenkürzel
+action-command-palette = Befehlspalette
+
+## Command palette
+
+palette-hint = Aktion oder Bahnhof eingeben
+palette-station = Bahnhof: { $station } ({ $abbrev })
+palette-nothing = Keine Treffer.
+
+## Stations
+
+station-cs = Stadt Süd
+station-cw = Stadt West
+station-cme = Kohlemine Ost
+station-cms = Kohlemine Süd
+station-cp = Kohlekraftwerk
+station-fm = Bauernhof
+station-ff = Lebensmittelfabrik & Stadt
+station-frc = Wald Mitte
+station-frs = Wald Süd
+station-gf = Warenfabrik & Stadt
+station-hb = Hafen & Stadt
+station-ime = Eisenerzmine Ost
+station-imw = Eisenerzmine West
+station-mf = Maschinenfabrik & Stadt
+station-mb = Militärbasis
+station-or = Ölraffinerie
+station-owc = Ölquelle Mitte
+station-own = Ölquelle Nord
+station-sw = Sägewerk
+station-sm = Stahlwerk
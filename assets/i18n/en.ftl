@@ -0,0 +1,322 @@
+# English. This is the fallback catalog: every key used by the app must be here.
+
+language = Language
+
+## Menu bar
+
+menu-add-locomotive = Add Locomotive/Car
+menu-add-order = Add Order
+menu-paste-jobs = Paste Jobs
+menu-station-map = Station Map
+menu-trip = Trip
+menu-suggest-power = Suggest power
+menu-job-board = Job Board
+menu-checks = Checks
+menu-checks-count = Checks ({ $count })
+menu-undo = Undo
+menu-redo = Redo
+menu-shortcuts = Shortcuts
+
+## Common buttons
+
+button-add = Add
+button-edit = Edit
+button-cancel = Cancel
+
+## Locomotives panel
+
+locomotives-heading = Current Locomotives
+delete-locomotive = Delete locomotive
+add-loco-heading = Add Locomotive/Car
+add-loco-label = Locomotive/Car
+loco-quantity = Number of identical units
+loco-details-weight = Weight: { $weight }
+loco-details-length = Length: { $length }
+loco-unpowered = Adds weight but no pulling power
+loco-conditions-pull = Pulls { $weight }
+loco-contribution = Share of the consist's pulling power in these conditions: { $percent }%
+placement-label = Position
+placement-head = Head end
+placement-after-cut = After { $cut }
+placement-rear = Rear
+loco-reversed = Running in reverse
+loco-details-speed = Max speed: { $speed } km/h
+
+## Consist info panel
+
+info-heading = Consist Info
+info-total-weight = - Total Weight: { $weight }
+info-still-to-pick-up = - Still to pick up: { $weight }
+info-supported-in-conditions = - Supported in these conditions: { $weight }
+info-supported-weights = Rated weights
+info-supported-weight = { $rating }: { $weight }
+info-total-length = - Total Length: { $length }
+info-power-single = - Power: all in one place
+info-power-distributed = - Power: distributed over { $positions } places
+info-max-speed = - Max speed: { $speed } km/h ({ $limiter })
+limiter-reversed = { $loco } in reverse
+limiter-car-type = { $car } cars
+limiter-hazmat = hazmat in { $order }
+limiter-order = limit on { $order }
+rating-zero-grade = 0% grade
+rating-two-grade = 2% grade
+rating-two-grade-rain = 2% grade in rain
+warning-no-power = The consist has no powered locomotive
+warning-overweight = Total weight { $weight } exceeds the { $supported } supported on a { $rating }
+warning-stall = Would stall climbing { $grade }% between { $from } and { $to }
+warning-slow-locomotive = The { $loco } limits the train to { $speed } km/h
+warning-slow-locomotive-reversed = The { $loco } running in reverse limits the train to { $speed } km/h
+
+## Conditions bar
+
+conditions-grade = Grade:
+conditions-weather = Weather
+conditions-sanding = Sanding
+conditions-coupler-limit = Coupler limit
+conditions-coupler-limit-hint = Caps what power in one place can move at the most one coupler can pull or push. This is an estimate, not a figure from the game, so it is off by default; { $default } is a starting point. Power spread through the train can move more.
+weather-dry = Dry
+weather-rain = Rain
+weather-snow = Snow
+weather-leaves = Leaves on the line
+verdict-ok = ✔ Can make it: { $weight } of { $supported } ({ $percent }%)
+verdict-too-heavy = ✖ Too heavy: { $weight } of { $supported }, { $excess } over
+verdict-no-power = ✖ No power to pull the train
+
+## Consist checks
+
+checks-heading = Consist checks
+checks-passed = ✔ No problems found
+checks-rules = Rules
+severity-info = Info
+severity-warning = Warning
+severity-error = Error
+rule-caboose-last = Caboose must be last
+rule-hazmat-next-to-locomotive = No hazmat cut next to a locomotive or the caboose
+rule-hazmat-separation = Buffer cars between different hazmat classes
+rule-slug-next-to-de6 = A DE6 Slug must be next to a DE6
+rule-zero-weight-order = No order without weight
+violation-caboose-last = The caboose isn't at the end of the train
+violation-hazmat-next-to-locomotive = Hazmat cut { $order } is next to a locomotive or the caboose
+violation-hazmat-separation = Hazmat cuts { $order } and { $other } need buffer cars between them
+violation-slug-next-to-de6 = A DE6 Slug isn't coupled next to a DE6
+violation-zero-weight-order = Order { $order } has no weight
+checks-suggestion = Safe order of the cuts: { $cuts }
+checks-apply = Reorder
+checks-no-safe-order = No order of the cuts keeps the hazmat safe with the locomotives where they are.
+hazmat-flammable = Flammable
+hazmat-explosive = Explosive
+hazmat-gas = Gas
+
+## Suggest power
+
+power-heading = Suggest power
+power-load = Heaviest load on the route: { $weight }
+power-goal = Prefer
+power-goal-fewest = Fewest units
+power-goal-lightest = Lightest
+power-goal-cheapest = Cheapest to run (estimate)
+power-none = No combination of up to { $units } locomotives can pull this load in these conditions.
+power-details = Train { $weight } of { $supported } it can pull, about ${ $cost } an hour to run
+power-margin = { $weight } to spare
+power-apply = Use
+power-roster = Licenses and owned locomotives
+power-licensed = Licensed
+power-owned = Owned:
+
+## Job board
+
+job-board-heading = Job Board
+job-board-empty = No jobs on the board yet. Paste some in with Paste Jobs.
+job-board-all-stations = All stations ({ $count })
+job-board-spare = Room for { $weight } more
+job-board-spare-length = Room for { $weight } and { $length } more
+job-board-license = Length license up to
+job-board-track = Shortest track at each station
+job-board-goal = Pick for
+job-board-goal-payout = Highest payout
+job-board-goal-cars = Most cars
+job-board-pick = Pick best
+job-board-picked = Picked: { $weight }, { $length }, ${ $payout }
+job-board-too-much = The picked jobs don't fit the train
+job-board-take = Take { $count } jobs
+job-board-take-one = Take
+job-board-remove = Remove
+job-board-cars = Cars
+job-board-payout = Payout
+
+## Orders table
+
+orders-heading = Orders
+orders-showing = Showing { $shown } of { $total } orders: { $weight }, { $length }
+delivered-heading = Delivered ({ $count })
+column-order-name = Order Name
+column-weight = Weight
+column-length = Length
+column-pickup-station = Pickup Station
+column-pickup-track = Pickup Track
+column-dropoff-station = Dropoff Station
+column-dropoff-track = Dropoff Track
+column-status = Status
+sort-hint = Click to sort
+order-menu-add = Add order...
+order-menu-above = Above
+order-menu-below = Below
+order-menu-move = Move order...
+order-menu-up = Up
+order-menu-down = Down
+order-menu-edit = Edit order
+order-menu-to-board = Move to job board
+order-menu-delete = Delete order
+status-planned = Planned
+status-picked-up = On train
+status-delivered = Delivered
+filter-search = Search:
+filter-search-hint = Name, job ID or track
+filter-pickup = Pickup:
+filter-dropoff = Dropoff:
+filter-station = To or from { $station }
+filter-clear = Clear filters
+filter-any = Any
+
+## Order form
+
+order-form-add-heading = Add Order
+order-form-edit-heading = Edit Order
+order-form-name = Order Name
+order-form-job-id = Job ID
+order-form-weight = Weight ({ $unit })
+order-form-length = Length ({ $unit })
+order-form-cars = Cars
+order-form-car-type = Car Type
+order-form-car-type-unknown = Unknown
+order-form-cargo = Cargo
+order-form-hazmat = Hazmat class
+order-form-hazmat-none = None
+order-form-speed-limit = Speed limit (km/h, optional)
+order-form-payout = Payout ($, optional)
+order-form-pickup-station = Pickup Station:
+order-form-pickup-track = Pickup Track
+order-form-dropoff-station = Dropoff Station:
+order-form-dropoff-track = Dropoff Track
+order-form-status = Status
+error-required = Required
+error-out-of-range = Must be more than 0 and at most { $max } { $unit }
+error-not-a-number = Not a number
+error-track-format = Expected a track like B7L
+error-car-count = Must be a whole number up to { $max }
+error-speed-limit = Must be a whole number of km/h up to { $max }
+error-payout = Must be a whole number of dollars up to { $max }
+error-same-track = Must differ from the pickup track
+
+## Units
+
+units-mass = Weight unit
+units-length = Length unit
+unit-tonnes = Metric tonnes
+unit-short-tons = US short tons
+unit-meters = Meters
+unit-feet = Feet
+
+## Consist strip
+
+strip-pickup = Pickup: { $station } { $track }
+strip-dropoff = Dropoff: { $station } { $track }
+
+## Trip
+
+trip-heading = Trip
+trip-start-hint = Pick the station the train is at to start the trip.
+trip-previous = Previous stop
+trip-next = Next stop
+trip-not-started = Not started
+trip-route = Route: { $stops }
+trip-set-out = Set out at { $station }
+trip-nothing-to-set-out = Nothing to set out.
+trip-set-out-order = - { $order } ({ $cars } cars) → { $track }
+trip-pick-up = Pick up at { $station }
+trip-nothing-to-pick-up = Nothing to pick up.
+trip-pick-up-order = - { $order } ({ $cars } cars) from { $track }
+trip-next-leg = Next leg: to { $station }
+trip-last-leg = Next leg: no more stops
+trip-weight = - Weight: { $weight }
+trip-length = - Length: { $length }
+trip-advance = Work station and advance
+trip-profile = Route profile
+trip-profile-stalls = Stalls here
+trip-profile-too-heavy = Too heavy
+grades-provisional = Grades are estimates, not measured from the game.
+
+## Station map
+
+map-heading = Station Map
+map-reset = Reset view
+map-hint = Drag to pan, scroll to zoom, click a station to filter the orders.
+
+## Paste jobs
+
+paste-heading = Paste Jobs
+paste-hint = Paste jobs from the job overview, one per line or one block per job.
+paste-parse = Parse
+paste-add = Add { $count } orders
+paste-add-to-board = Add to job board
+paste-empty = No jobs parsed yet.
+paste-pickup = Pickup
+paste-dropoff = Dropoff
+paste-track = Track
+paste-remove = Remove
+
+## Keyboard shortcuts
+
+shortcuts-heading = Keyboard Shortcuts
+shortcuts-recording = Press a key… (Esc to cancel)
+shortcuts-set = Set
+shortcuts-clear = Clear
+shortcuts-reset = Reset to defaults
+action-add-order = Add order
+action-add-locomotive = Add locomotive/car
+action-paste-jobs = Paste jobs
+action-edit-selected = Edit selected order
+action-delete-selected = Delete selected
+action-move-up = Move selected up
+action-move-down = Move selected down
+action-select-previous = Select previous
+action-select-next = Select next
+action-toggle-power = Toggle power of selected locomotive
+action-undo = Undo
+action-redo = Redo
+action-station-map = Show/hide station map
+action-trip = Show/hide trip
+action-checks = Show/hide consist checks
+action-suggest-power = Suggest power
+action-job-board = Show/hide job board
+action-shortcuts = Keyboard shortcuts
+action-command-palette = Command palette
+
+## Command palette
+
+palette-hint = Type an action or station
+palette-station = Station: { $station } ({ $abbrev })
+palette-nothing = Nothing matches.
+
+## Stations
+
+station-cs = City South
+station-cw = City West
+station-cme = Coal Mine East
+station-cms = Coal Mine South
+station-cp = Coal Power Plant
+station-fm = Farm
+station-ff = Food Factory & Town
+station-frc = Forest Central
+station-frs = Forest South
+station-gf = Goods Factory & Town
+station-hb = Harbor & Town
+station-ime = Iron Ore Mine East
+station-imw = Iron Ore Mine West
+station-mf = Machine Factory & Town
+station-mb = Military Base
+station-or = Oil Refinery
+station-owc = Oil Well Central
+station-own = Oil Well North
+station-sw = Sawmill
+station-sm = Steel Mill
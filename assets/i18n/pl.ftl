@@ -0,0 +1,323 @@
+# Polski
+
+language = Język
+
+## Menu bar
+
+menu-add-locomotive = Dodaj lokomotywę/wagon
+menu-add-order = Dodaj zlecenie
+menu-paste-jobs = Wklej zlecenia
+menu-station-map = Mapa stacji
+menu-trip = Kurs
+menu-suggest-power = Dobierz lokomotywy
+menu-job-board = Tablica zleceń
+menu-checks = Kontrola
+menu-checks-count = Kontrola ({ $count })
+menu-undo = Cofnij
+menu-redo = Ponów
+menu-shortcuts = Skróty klawiszowe
+
+## Common buttons
+
+button-add = Dodaj
+button-edit = Zmień
+button-cancel = Anuluj
+
+## Locomotives panel
+
+locomotives-heading = Lokomotywy
+delete-locomotive = Usuń lokomotywę
+add-loco-heading = Dodaj lokomotywę/wagon
+add-loco-label = Lokomotywa/wagon
+loco-quantity = Liczba identycznych pojazdów
+loco-details-weight = Masa: { $weight }
+loco-details-length = Długość: { $length }
+loco-unpowered = Dodaje masę, ale nie ciągnie
+loco-conditions-pull = Uciągnie { $weight }
+loco-contribution = Udział w sile pociągowej składu w tych warunkach: { $percent }%
+placement-label = Pozycja
+placement-head = Czoło składu
+placement-after-cut = Za: { $cut }
+placement-rear = Koniec składu
+loco-reversed = Jazda tyłem
+loco-details-speed = Prędkość maksymalna: { $speed } km/h
+
+## Consist info panel
+
+info-heading = Dane składu
+info-total-weight = - Masa całkowita: { $weight }
+info-still-to-pick-up = - Do odebrania: { $weight }
+info-supported-in-conditions = - Dopuszczalna masa w tych warunkach: { $weight }
+info-supported-weights = Masy znamionowe
+# Only placeholders, the same in every language.
+info-supported-weight = { $rating }: { $weight }
+info-total-length = - Długość całkowita: { $length }
+info-power-single = - Napęd: w jednym miejscu
+info-power-distributed = - Napęd: w { $positions } miejscach
+info-max-speed = - Prędkość maksymalna: { $speed } km/h ({ $limiter })
+limiter-reversed = { $loco } jadąca tyłem
+limiter-car-type = wagony typu { $car }
+limiter-hazmat = materiały niebezpieczne w { $order }
+limiter-order = ograniczenie zlecenia { $order }
+rating-zero-grade = nachylenie 0%
+rating-two-grade = nachylenie 2%
+rating-two-grade-rain = nachylenie 2% w deszczu
+warning-no-power = Skład nie ma napędzanej lokomotywy
+warning-overweight = Masa całkowita { $weight } przekracza dopuszczalne { $supported } przy: { $rating }
+warning-stall = Utknie na wzniesieniu { $grade }% między { $from } a { $to }
+warning-slow-locomotive = { $loco } ogranicza prędkość pociągu do { $speed } km/h
+warning-slow-locomotive-reversed = { $loco } jadąca tyłem ogranicza prędkość pociągu do { $speed } km/h
+
+## Conditions bar
+
+conditions-grade = Nachylenie:
+conditions-weather = Pogoda
+conditions-sanding = Piasecznice
+conditions-coupler-limit = Limit sprzęgu
+conditions-coupler-limit-hint = Ogranicza to, co trakcja w jednym miejscu może poruszyć, do tego, ile jeden sprzęg może ciągnąć lub pchać. To szacunek, a nie wartość z gry, więc domyślnie jest wyłączony; { $default } to wartość początkowa. Trakcja rozłożona w pociągu może poruszyć więcej.
+weather-dry = Sucho
+weather-rain = Deszcz
+weather-snow = Śnieg
+weather-leaves = Liście na torach
+verdict-ok = ✔ Da radę: { $weight } z { $supported } ({ $percent }%)
+verdict-too-heavy = ✖ Za ciężki: { $weight } z { $supported }, { $excess } za dużo
+verdict-no-power = ✖ Brak napędu
+
+## Consist checks
+
+checks-heading = Kontrola składu
+checks-passed = ✔ Nie znaleziono problemów
+checks-rules = Reguły
+severity-info = Informacja
+severity-warning = Ostrzeżenie
+severity-error = Błąd
+rule-caboose-last = Wagon służbowy musi być na końcu
+rule-hazmat-next-to-locomotive = Żadnych materiałów niebezpiecznych obok lokomotywy lub wagonu służbowego
+rule-hazmat-separation = Wagony ochronne między różnymi klasami materiałów niebezpiecznych
+rule-slug-next-to-de6 = DE6 Slug musi być obok DE6
+rule-zero-weight-order = Żadnych zleceń bez masy
+violation-caboose-last = Wagon służbowy nie jest na końcu pociągu
+violation-hazmat-next-to-locomotive = Grupa wagonów z materiałami niebezpiecznymi { $order } jest obok lokomotywy lub wagonu służbowego
+violation-hazmat-separation = Między grupami wagonów { $order } i { $other } brakuje wagonów ochronnych
+violation-slug-next-to-de6 = DE6 Slug nie jest sprzęgnięty obok DE6
+violation-zero-weight-order = Zlecenie { $order } nie ma masy
+checks-suggestion = Bezpieczna kolejność grup wagonów: { $cuts }
+checks-apply = Zmień kolejność
+checks-no-safe-order = Żadna kolejność grup wagonów nie zapewnia bezpieczeństwa przy tym ustawieniu lokomotyw.
+hazmat-flammable = Łatwopalne
+hazmat-explosive = Wybuchowe
+hazmat-gas = Gazy
+
+## Suggest power
+
+power-heading = Dobierz lokomotywy
+power-load = Największe obciążenie na trasie: { $weight }
+power-goal = Preferuj
+power-goal-fewest = Najmniej pojazdów
+power-goal-lightest = Najlżejsze
+power-goal-cheapest = Najtańsze w eksploatacji (szacunkowo)
+power-none = Żadne zestawienie do { $units } lokomotyw nie uciągnie tej masy w tych warunkach.
+power-details = Pociąg { $weight } z { $supported } możliwych, koszt ok. ${ $cost } za godzinę
+power-margin = { $weight } zapasu
+power-apply = Użyj
+power-roster = Licencje i posiadane lokomotywy
+power-licensed = Licencja
+power-owned = Posiadane:
+
+## Job board
+
+job-board-heading = Tablica zleceń
+job-board-empty = Na tablicy nie ma jeszcze zleceń. Dodaj je przez „Wklej zlecenia”.
+job-board-all-stations = Wszystkie stacje ({ $count })
+job-board-spare = Miejsce na jeszcze { $weight }
+job-board-spare-length = Miejsce na jeszcze { $weight } i { $length }
+job-board-license = Licencja długości do
+job-board-track = Najkrótszy tor na każdej stacji
+job-board-goal = Wybierz według
+job-board-goal-payout = Najwyższe wynagrodzenie
+job-board-goal-cars = Najwięcej wagonów
+job-board-pick = Wybierz najlepsze
+job-board-picked = Wybrane: { $weight }, { $length }, ${ $payout }
+job-board-too-much = Wybrane zlecenia nie mieszczą się w pociągu
+job-board-take = Przyjmij zlecenia ({ $count })
+job-board-take-one = Przyjmij
+job-board-remove = Usuń
+job-board-cars = Wagony
+job-board-payout = Wynagrodzenie
+
+## Orders table
+
+orders-heading = Zlecenia
+orders-showing = Widoczne { $shown } z { $total } zleceń: { $weight }, { $length }
+delivered-heading = Dostarczone ({ $count })
+column-order-name = Zlecenie
+column-weight = Masa
+column-length = Długość
+column-pickup-station = Stacja odbioru
+column-pickup-track = Tor odbioru
+column-dropoff-station = Stacja docelowa
+column-dropoff-track = Tor docelowy
+column-status = Stan
+sort-hint = Kliknij, aby posortować
+order-menu-add = Dodaj zlecenie...
+order-menu-above = Powyżej
+order-menu-below = Poniżej
+order-menu-move = Przesuń zlecenie...
+order-menu-up = W górę
+order-menu-down = W dół
+order-menu-edit = Edytuj zlecenie
+order-menu-to-board = Przenieś na tablicę zleceń
+order-menu-delete = Usuń zlecenie
+status-planned = Zaplanowane
+status-picked-up = W składzie
+status-delivered = Dostarczone
+filter-search = Szukaj:
+filter-search-hint = Nazwa, ID zlecenia lub tor
+filter-pickup = Odbiór:
+filter-dropoff = Cel:
+filter-station = Z lub do: { $station }
+filter-clear = Wyczyść filtry
+filter-any = Dowolna
+
+## Order form
+
+order-form-add-heading = Dodaj zlecenie
+order-form-edit-heading = Edytuj zlecenie
+order-form-name = Nazwa zlecenia
+order-form-job-id = ID zlecenia
+order-form-weight = Masa ({ $unit })
+order-form-length = Długość ({ $unit })
+order-form-cars = Wagony
+order-form-car-type = Typ wagonu
+order-form-car-type-unknown = Nieznany
+order-form-cargo = Ładunek
+order-form-hazmat = Klasa materiału niebezpiecznego
+order-form-hazmat-none = Brak
+order-form-speed-limit = Ograniczenie prędkości (km/h, opcjonalne)
+order-form-payout = Wynagrodzenie ($, opcjonalne)
+order-form-pickup-station = Stacja odbioru:
+order-form-pickup-track = Tor odbioru
+order-form-dropoff-station = Stacja docelowa:
+order-form-dropoff-track = Tor docelowy
+order-form-status = Stan
+error-required = Pole wymagane
+error-out-of-range = Musi być większe od 0 i najwyżej { $max } { $unit }
+error-not-a-number = To nie jest liczba
+error-track-format = Oczekiwano toru w formacie B7L
+error-car-count = Musi być liczbą całkowitą do { $max }
+error-speed-limit = Musi być liczbą całkowitą do { $max } km/h
+error-payout = Musi być liczbą całkowitą do { $max } dolarów
+error-same-track = Musi różnić się od toru odbioru
+
+## Units
+
+units-mass = Jednostka masy
+units-length = Jednostka długości
+unit-tonnes = Tony metryczne
+unit-short-tons = Tony amerykańskie (short tons)
+unit-meters = Metry
+unit-feet = Stopy
+
+## Consist strip
+
+strip-pickup = Odbiór: { $station } { $track }
+strip-dropoff = Dostawa: { $station } { $track }
+
+## Trip
+
+trip-heading = Kurs
+trip-start-hint = Wybierz stację, na której stoi pociąg, aby rozpocząć kurs.
+trip-previous = Poprzedni postój
+trip-next = Następny postój
+trip-not-started = Nie rozpoczęto
+trip-route = Trasa: { $stops }
+trip-set-out = Odstaw na { $station }
+trip-nothing-to-set-out = Nic do odstawienia.
+trip-set-out-order = - { $order } (wagony: { $cars }) → { $track }
+trip-pick-up = Zabierz z { $station }
+trip-nothing-to-pick-up = Nic do zabrania.
+trip-pick-up-order = - { $order } (wagony: { $cars }) z { $track }
+trip-next-leg = Następny odcinek: do { $station }
+trip-last-leg = Następny odcinek: brak kolejnych postojów
+trip-weight = - Masa: { $weight }
+trip-length = - Długość: { $length }
+trip-advance = Obsłuż stację i jedź dalej
+trip-profile = Profil trasy
+trip-profile-stalls = Tu utknie
+trip-profile-too-heavy = Za ciężki
+grades-provisional = Nachylenia są szacunkowe, a nie zmierzone w grze.
+
+## Station map
+
+map-heading = Mapa stacji
+map-reset = Resetuj widok
+map-hint = Przeciągnij, aby przesunąć, przewiń, aby przybliżyć, kliknij stację, aby filtrować zlecenia.
+
+## Paste jobs
+
+paste-heading = Wklej zlecenia
+paste-hint = Wklej zlecenia z przeglądu zleceń, jedno na wiersz lub jeden blok na zlecenie.
+paste-parse = Odczytaj
+paste-add = Dodaj zlecenia: { $count }
+paste-add-to-board = Dodaj do tablicy zleceń
+paste-empty = Nie odczytano jeszcze żadnych zleceń.
+paste-pickup = Odbiór
+paste-dropoff = Dostawa
+paste-track = Tor
+paste-remove = Usuń
+
+## Keyboard shortcuts
+
+shortcuts-heading = Skróty klawiszowe
+shortcuts-recording = Naciśnij klawisz… (Esc, aby anulować)
+shortcuts-set = Ustaw
+shortcuts-clear = Wyczyść
+shortcuts-reset = Przywróć domyślne
+action-add-order = Dodaj zlecenie
+action-add-locomotive = Dodaj lokomotywę/wagon
+action-paste-jobs = Wklej zlecenia
+action-edit-selected = Edytuj wybrane zlecenie
+action-delete-selected = Usuń zaznaczenie
+action-move-up = Przesuń zaznaczenie w górę
+action-move-down = Przesuń zaznaczenie w dół
+action-select-previous = Zaznacz poprzedni
+action-select-next = Zaznacz następny
+action-toggle-power = Przełącz napęd wybranej lokomotywy
+action-undo = Cofnij
+action-redo = Ponów
+action-station-map = Pokaż/ukryj mapę stacji
+action-trip = Pokaż/ukryj kurs
+action-checks = Pokaż/ukryj kontrole składu
+action-suggest-power = Zaproponuj trakcję
+action-job-board = Pokaż/ukryj tablicę zleceń
+action-shortcuts = Skróty klawiszowe
+action-command-palette = Paleta poleceń
+
+## Command palette
+
+palette-hint = Wpisz akcję lub stację
+palette-station = Stacja: { $station } ({ $abbrev })
+palette-nothing = Brak wyników.
+
+## Stations
+
+station-cs = Miasto Południe
+station-cw = Miasto Zachód
+station-cme = Kopalnia węgla Wschód
+station-cms = Kopalnia węgla Południe
+station-cp = Elektrownia węglowa
+station-fm = Farma
+station-ff = Fabryka żywności i miasto
+station-frc = Las Centrum
+station-frs = Las Południe
+station-gf = Fabryka towarów i miasto
+station-hb = Port i miasto
+station-ime = Kopalnia rudy żelaza Wschód
+station-imw = Kopalnia rudy żelaza Zachód
+station-mf = Fabryka maszyn i miasto
+station-mb = Baza wojskowa
+station-or = Rafineria
+station-owc = Szyb naftowy Centrum
+station-own = Szyb naftowy Północ
+station-sw = Tartak
+station-sm = Huta stali
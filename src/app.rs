@@ -1,6 +1,27 @@
-use crate::locomotive::{LOCO_LIST, Locomotive, LocomotiveInfo, locomotives};
-use crate::order::{Order, OrderModal, OrderModalMode};
+use std::ops::Range;
+
+use crate::checks_window::{ChecksWindow, severity_color};
+use crate::conditions::{Conditions, DEFAULT_COUPLER_LIMIT, MAX_GRADE, Verdict, WEATHERS, Weather};
+use crate::consist::{Consist, ConsistSummary, ConsistWarning, RATINGS};
+use crate::consist_strip::consist_strip;
+use crate::history::{Command, History};
+use crate::i18n::{I18n, LANGUAGES, Language};
+use crate::job_board::JobBoardWindow;
+use crate::jobs::LengthLimits;
+use crate::keymap::{Action, Keymap, ShortcutsWindow};
+use crate::locomotive::{LOCO_LIST, Locomotive, LocomotiveInfo, Placement, locomotives};
+use crate::order::{Order, OrderModal, OrderModalMode, OrderStatus};
+use crate::order_table::{OrderFilter, SortColumn, status_combo};
+use crate::palette::{CommandPalette, PaletteEntry};
+use crate::paste_jobs::PasteJobsModal;
+use crate::power::Roster;
+use crate::power_window::PowerWindow;
+use crate::route::{Leg, legs};
+use crate::rules::{RuleConfig, check};
+use crate::station_map::StationMap;
 use crate::toggle_switch::toggle;
+use crate::trip::TripMode;
+use crate::units::{Length, Mass, Units};
 use egui_extras::{Column, TableBuilder};
 
 /// We derive Deserialize/Serialize so we can persist app state on shutdown.
@@ -14,16 +35,49 @@ pub struct ConsistManagerApp {
     new_order_modal: OrderModal,
     #[serde(skip)]
     edit_order_modal: OrderModal,
+    #[serde(skip)]
+    paste_jobs_modal: PasteJobsModal,
+    #[serde(skip)]
+    history: History,
+    #[serde(skip)]
+    station_map: StationMap,
+    #[serde(skip)]
+    shortcuts_window: ShortcutsWindow,
+    #[serde(skip)]
+    checks_window: ChecksWindow,
+    rules: RuleConfig,
+    #[serde(skip)]
+    power_window: PowerWindow,
+    roster: Roster,
+    #[serde(skip)]
+    job_board: JobBoardWindow,
+    length_limits: LengthLimits,
+    #[serde(skip)]
+    palette: CommandPalette,
+    keymap: Keymap,
+    units: Units,
+    conditions: Conditions,
+    language: Language,
+    #[serde(skip)]
+    i18n: I18n,
 
-    locomotives: Vec<LocomotiveInfo>,
-    pub orders: Vec<Order>,
+    pub consist: Consist,
     order_index: Option<usize>,
+    #[serde(skip)]
+    order_filter: OrderFilter,
+    #[serde(skip)]
+    selection: Option<Selection>,
+    trip: TripMode,
+
+    // State saved before the consist moved into its own struct, read once so
+    // that it isn't lost on upgrade.
+    #[serde(rename = "locomotives", skip_serializing)]
+    legacy_locomotives: Vec<LocomotiveInfo>,
+    #[serde(rename = "orders", skip_serializing)]
+    legacy_orders: Vec<Order>,
 
-    total_weight: f32,
-    total_length: f32,
-    supported_weight_0_deg: u16,
-    supported_weight_2_deg: u16,
-    supported_weight_rain: u16,
+    #[serde(skip)]
+    summary: ConsistSummary,
 }
 
 impl Default for ConsistManagerApp {
@@ -37,16 +91,33 @@ impl Default for ConsistManagerApp {
 
             new_order_modal: OrderModal::new(OrderModalMode::New),
             edit_order_modal: OrderModal::new(OrderModalMode::Edit),
+            paste_jobs_modal: PasteJobsModal::default(),
+            history: History::default(),
+            station_map: StationMap::default(),
+            shortcuts_window: ShortcutsWindow::default(),
+            checks_window: ChecksWindow::default(),
+            rules: RuleConfig::default(),
+            power_window: PowerWindow::default(),
+            roster: Roster::default(),
+            job_board: JobBoardWindow::default(),
+            length_limits: LengthLimits::default(),
+            palette: CommandPalette::default(),
+            keymap: Keymap::default(),
+            units: Units::default(),
+            conditions: Conditions::default(),
+            language: Language::default(),
+            i18n: I18n::default(),
 
-            locomotives: Vec::new(),
-            orders: Vec::new(),
+            consist: Consist::default(),
             order_index: None,
+            order_filter: OrderFilter::default(),
+            selection: None,
+            trip: TripMode::default(),
 
-            total_weight: 0.0,
-            total_length: 0.0,
-            supported_weight_0_deg: 0,
-            supported_weight_2_deg: 0,
-            supported_weight_rain: 0,
+            legacy_locomotives: Vec::new(),
+            legacy_orders: Vec::new(),
+
+            summary: ConsistSummary::default(),
         }
     }
 }
@@ -56,40 +127,647 @@ impl ConsistManagerApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         // Load previous app state (if any).
         // Note that you must enable the `persistence` feature for this to work.
-        if let Some(storage) = cc.storage {
+        let mut app: Self = if let Some(storage) = cc.storage {
             eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
         } else {
             Default::default()
+        };
+        if !app.legacy_locomotives.is_empty() {
+            app.consist.locomotives = std::mem::take(&mut app.legacy_locomotives);
+        }
+        if !app.legacy_orders.is_empty() {
+            app.consist.orders = std::mem::take(&mut app.legacy_orders);
         }
+        app.i18n = I18n::new(app.language);
+        app.recalc();
+        app
     }
 
-    // Recalculates the weight maximums of the current train consist.
-    pub fn recalc_loco_limits(&mut self) {
-        let weight_0_deg = self
-            .locomotives
-            .iter()
-            .fold(0, |a, l| if l.powered { a + l.zero_grade_t } else { a });
-        let weight_2_deg = self
-            .locomotives
+    // Recalculates the summary of the current train consist, and drops the
+    // selection if what was selected is gone.
+    fn recalc(&mut self) {
+        self.summary = self.consist.summary(self.conditions.coupler_limit);
+        let selection_exists = match self.selection {
+            Some(Selection::Locomotive(ix)) => ix < self.consist.locomotives.len(),
+            Some(Selection::Order(ix)) => ix < self.consist.orders.len(),
+            None => true,
+        };
+        if !selection_exists {
+            self.selection = None;
+        }
+    }
+
+    // The orders listed in the table, in the order they are listed.
+    fn visible_orders(&self) -> Vec<usize> {
+        self.order_filter.open_orders(&self.consist.orders)
+    }
+
+    // Applies an edit to the consist and records it in the undo history.
+    fn execute(&mut self, command: Command) {
+        self.history.execute(command, &mut self.consist);
+        self.recalc();
+    }
+
+    // Lists the delivered orders in a collapsible section at the bottom of the panel.
+    fn delivered_ui(&mut self, ui: &mut egui::Ui) {
+        let delivered: Vec<usize> = self
+            .consist
+            .orders
             .iter()
-            .fold(0, |a, l| if l.powered { a + l.two_grade_t } else { a });
-        let weight_rain = self
+            .enumerate()
+            .filter(|(_, o)| o.status == OrderStatus::Delivered)
+            .map(|(ix, _)| ix)
+            .collect();
+        if delivered.is_empty() {
+            return;
+        }
+        let mut command = None;
+        egui::TopBottomPanel::bottom("delivered_orders")
+            .resizable(true)
+            .show_inside(ui, |ui| {
+                let heading = self.i18n.tr_args(
+                    "delivered-heading",
+                    &[("count", delivered.len().to_string())],
+                );
+                egui::CollapsingHeader::new(heading)
+                    .id_salt("delivered_orders_header")
+                    .show(ui, |ui| {
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            egui::Grid::new("delivered_orders_grid")
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for ix in delivered {
+                                        let Some(order) = self.consist.orders.get(ix) else {
+                                            continue;
+                                        };
+                                        ui.label(&order.name);
+                                        ui.label(self.units.mass(order.weight));
+                                        ui.label(self.units.length(order.length));
+                                        ui.label(format!(
+                                            "{}-{}",
+                                            order.dropoff_station.to_abbrev(),
+                                            order.dropoff_track
+                                        ));
+                                        let id = egui::Id::new("delivered_status").with(ix);
+                                        if let Some(new) =
+                                            status_combo(ui, &self.i18n, id, order.status)
+                                        {
+                                            command = Some(Command::SetOrderStatus {
+                                                index: ix,
+                                                old: order.status,
+                                                new,
+                                            });
+                                        }
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                    });
+            });
+        if let Some(command) = command {
+            self.execute(command);
+        }
+    }
+
+    fn undo(&mut self) {
+        if self.history.undo(&mut self.consist) {
+            self.recalc();
+        }
+    }
+
+    fn redo(&mut self) {
+        if self.history.redo(&mut self.consist) {
+            self.recalc();
+        }
+    }
+
+    // Adds several orders to the end of the list as a single undoable edit.
+    fn append_orders(&mut self, orders: Vec<Order>) {
+        let first = self.consist.orders.len();
+        let commands = orders
+            .into_iter()
+            .enumerate()
+            .map(|(i, order)| Command::AddOrder {
+                index: first + i,
+                order,
+            })
+            .collect();
+        self.execute(Command::Batch(commands));
+    }
+
+    // Adds jobs to the end of the job board as a single undoable edit.
+    fn append_jobs(&mut self, jobs: Vec<Order>) {
+        let first = self.consist.jobs.len();
+        let commands = jobs
+            .into_iter()
+            .enumerate()
+            .map(|(i, job)| Command::AddJob {
+                index: first + i,
+                job,
+            })
+            .collect();
+        self.execute(Command::Batch(commands));
+    }
+
+    // Runs the action bound to a pressed shortcut, unless a text field has
+    // focus or a dialog is open.
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        let dialog_open = self.add_loco_modal_open
+            || self.new_order_modal.open
+            || self.edit_order_modal.open
+            || self.paste_jobs_modal.open
+            || self.palette.open;
+        if ctx.wants_keyboard_input() || dialog_open || self.shortcuts_window.is_recording() {
+            return;
+        }
+        if let Some(action) = self.keymap.consume(ctx) {
+            self.run_action(action);
+        }
+    }
+
+    // Does what an action from a shortcut or the command palette asks for.
+    fn run_action(&mut self, action: Action) {
+        match action {
+            Action::AddOrder => {
+                self.new_order_modal.open = true;
+                self.order_index = None;
+            }
+            Action::AddLocomotive => self.add_loco_modal_open = true,
+            Action::PasteJobs => self.paste_jobs_modal.open = true,
+            Action::EditSelected => {
+                if let Some(Selection::Order(ix)) = self.selection
+                    && let Some(order) = self.consist.orders.get(ix)
+                {
+                    self.edit_order_modal.init_from_order(order, ix);
+                    self.edit_order_modal.open = true;
+                }
+            }
+            Action::DeleteSelected => self.delete_selected(),
+            Action::MoveUp => self.move_selected(false),
+            Action::MoveDown => self.move_selected(true),
+            Action::SelectPrevious => self.step_selection(false),
+            Action::SelectNext => self.step_selection(true),
+            Action::TogglePower => {
+                if let Some(Selection::Locomotive(ix)) = self.selection
+                    && self
+                        .consist
+                        .locomotives
+                        .get(ix)
+                        .is_some_and(|l| l.has_power)
+                {
+                    self.execute(Command::TogglePower { index: ix });
+                }
+            }
+            Action::Undo => self.undo(),
+            Action::Redo => self.redo(),
+            Action::StationMap => self.station_map.open = !self.station_map.open,
+            Action::Trip => self.trip.open = !self.trip.open,
+            Action::Checks => self.checks_window.open = !self.checks_window.open,
+            Action::SuggestPower => self.power_window.open = !self.power_window.open,
+            Action::JobBoard => self.job_board.open = !self.job_board.open,
+            Action::Shortcuts => self.shortcuts_window.open = !self.shortcuts_window.open,
+            Action::CommandPalette => self.palette.toggle(),
+        }
+    }
+
+    // A menu bar button whose tooltip shows the action's shortcut.
+    fn menu_button(
+        &self,
+        ui: &mut egui::Ui,
+        label: &str,
+        action: Action,
+        enabled: bool,
+    ) -> egui::Response {
+        let response = ui.add_enabled(enabled, egui::Button::new(label));
+        match self.keymap.shortcut(action) {
+            Some(shortcut) => response.on_hover_text(ui.ctx().format_shortcut(&shortcut)),
+            None => response,
+        }
+    }
+
+    // The grade and weather to work out the train's capacity for, and
+    // whether the train can make it up the grade.
+    fn conditions_ui(&mut self, ui: &mut egui::Ui) {
+        let i18n = &self.i18n;
+        let mut limit_changed = false;
+        ui.horizontal_wrapped(|ui| {
+            ui.label(i18n.tr("conditions-grade"));
+            ui.add(
+                egui::Slider::new(&mut self.conditions.grade, 0.0..=MAX_GRADE)
+                    .step_by(0.1)
+                    .fixed_decimals(1)
+                    .suffix("%"),
+            );
+            ui.add_space(10.0);
+            egui::ComboBox::new("weather", i18n.tr("conditions-weather"))
+                .selected_text(weather_label(i18n, self.conditions.weather))
+                .show_ui(ui, |ui| {
+                    for weather in WEATHERS {
+                        ui.selectable_value(
+                            &mut self.conditions.weather,
+                            weather,
+                            weather_label(i18n, weather),
+                        );
+                    }
+                });
+            ui.add_space(10.0);
+            ui.checkbox(&mut self.conditions.sanding, i18n.tr("conditions-sanding"));
+            ui.add_space(10.0);
+            // The coupler limit is a guess, so it's off unless turned on.
+            let hint = i18n.tr_args(
+                "conditions-coupler-limit-hint",
+                &[("default", self.units.mass(DEFAULT_COUPLER_LIMIT))],
+            );
+            let mut limited = self.conditions.coupler_limit.is_some();
+            if ui
+                .checkbox(&mut limited, i18n.tr("conditions-coupler-limit"))
+                .on_hover_text(&hint)
+                .changed()
+            {
+                self.conditions.coupler_limit = limited.then_some(DEFAULT_COUPLER_LIMIT);
+                limit_changed = true;
+            }
+            if let Some(coupler_limit) = self.conditions.coupler_limit {
+                let unit = self.units.mass;
+                let mut limit = coupler_limit.in_unit(unit);
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut limit)
+                            .range(0.0..=f32::from(u16::MAX))
+                            .speed(10.0)
+                            .fixed_decimals(0)
+                            .suffix(format!(" {}", unit.symbol())),
+                    )
+                    .on_hover_text(hint)
+                    .changed()
+                {
+                    self.conditions.coupler_limit = Some(Mass::from_unit(limit, unit));
+                    limit_changed = true;
+                }
+            }
+            ui.separator();
+
+            let weight = self.summary.total_weight;
+            let supported = self.consist.supported_weight(&self.conditions);
+            let visuals = ui.visuals();
+            let (text, color) = match Verdict::new(weight.tonnes(), supported.tonnes()) {
+                Verdict::NoPower => (i18n.tr("verdict-no-power"), visuals.error_fg_color),
+                Verdict::Ok { load } => (
+                    i18n.tr_args(
+                        "verdict-ok",
+                        &[
+                            ("weight", self.units.mass(weight)),
+                            ("supported", self.units.mass(supported)),
+                            ("percent", format!("{:.0}", load * 100.0)),
+                        ],
+                    ),
+                    egui::Color32::from_rgb(60, 170, 80),
+                ),
+                Verdict::TooHeavy { excess } => (
+                    i18n.tr_args(
+                        "verdict-too-heavy",
+                        &[
+                            ("weight", self.units.mass(weight)),
+                            ("supported", self.units.mass(supported)),
+                            ("excess", self.units.mass(Mass::from_tonnes(excess))),
+                        ],
+                    ),
+                    visuals.error_fg_color,
+                ),
+            };
+            ui.label(egui::RichText::new(text).strong().color(color));
+        });
+        if limit_changed {
+            self.recalc();
+        }
+    }
+
+    // A combo box for the UI language, listing each language in its own name.
+    fn language_picker_ui(&mut self, ui: &mut egui::Ui) {
+        let language = self.language;
+        egui::ComboBox::from_id_salt("language")
+            .selected_text(language.to_string())
+            .width(80.0)
+            .show_ui(ui, |ui| {
+                for l in LANGUAGES {
+                    ui.selectable_value(&mut self.language, l, l.to_string());
+                }
+            })
+            .response
+            .on_hover_text(self.i18n.tr("language"));
+        if self.language != self.i18n.language() {
+            self.i18n = I18n::new(self.language);
+        }
+    }
+
+    // Lists the locomotives, with runs of identical units shown as one entry
+    // that expands to show the unit's details.
+    fn locomotives_ui(&mut self, ui: &mut egui::Ui) {
+        let mut command = None;
+        ui.vertical_centered(|ui| ui.heading(self.i18n.tr("locomotives-heading")));
+        ui.separator();
+        for group in self.consist.locomotive_groups() {
+            let Some(loco) = self.consist.locomotives.get(group.start).cloned() else {
+                continue;
+            };
+            let id = egui::Id::new("loco_group").with(group.start);
+            let state = egui::collapsing_header::CollapsingState::load_with_default_open(
+                ui.ctx(),
+                id,
+                false,
+            );
+            let (_, row, body) = state
+                .show_header(ui, |ui| {
+                    ui.dnd_drag_source(
+                        egui::Id::new("loco_drag").with(group.start),
+                        LocoDrag(group.start),
+                        |ui| ui.label("☰"),
+                    );
+                    let selected = matches!(
+                        self.selection,
+                        Some(Selection::Locomotive(ix)) if group.contains(&ix)
+                    );
+                    let response = ui.selectable_label(selected, loco.loco.to_string());
+                    if response.clicked() {
+                        self.selection = Some(Selection::Locomotive(group.start));
+                    }
+                    let mut quantity = group.len();
+                    let resp = ui
+                        .add(
+                            egui::DragValue::new(&mut quantity)
+                                .range(1..=MAX_IDENTICAL_LOCOMOTIVES)
+                                .prefix("×"),
+                        )
+                        .on_hover_text(self.i18n.tr("loco-quantity"));
+                    if resp.changed() {
+                        command = resize_group(&loco, group.clone(), quantity);
+                    }
+                    if loco.has_power {
+                        let mut powered = loco.powered;
+                        let resp = ui.add(toggle(&mut powered));
+                        if resp.changed() {
+                            command = Some(Command::Batch(
+                                group
+                                    .clone()
+                                    .map(|index| Command::TogglePower { index })
+                                    .collect(),
+                            ));
+                        }
+                    }
+                    egui::Popup::context_menu(&response)
+                        .id(egui::Id::new("loco_menu").with(group.start))
+                        .close_behavior(egui::PopupCloseBehavior::CloseOnClick)
+                        .show(|ui| {
+                            ui.set_min_width(200.0);
+                            if ui.button(self.i18n.tr("delete-locomotive")).clicked() {
+                                command = resize_group(&loco, group.clone(), 0);
+                            }
+                        });
+                })
+                .body(|ui| self.loco_details_ui(ui, &loco, group.clone()));
+            if let Some(details_command) = body.and_then(|body| body.inner) {
+                command = Some(details_command);
+            }
+            if let Some(dragged) = row.response.dnd_hover_payload::<LocoDrag>() {
+                paint_drop_marker(ui.painter(), row.response.rect, dragged.0, group.start);
+            }
+            if let Some(dragged) = row.response.dnd_release_payload::<LocoDrag>()
+                && let Some(from) = self.consist.locomotive_group(dragged.0)
+            {
+                command = move_group(from, group).or(command);
+            }
+        }
+        if let Some(command) = command {
+            self.execute(command);
+        }
+    }
+
+    // The weight, length, speed and ratings of a locomotive, with how much
+    // of the consist's pulling power its `group` of identical units
+    // provides. Returns the command for a newly picked placement or
+    // direction, applied to the whole group.
+    fn loco_details_ui(
+        &self,
+        ui: &mut egui::Ui,
+        loco: &LocomotiveInfo,
+        group: Range<usize>,
+    ) -> Option<Command> {
+        let i18n = &self.i18n;
+        let units = &self.units;
+        let count = group.len();
+        let mut command = self.placement_combo(ui, loco).map(|placement| {
+            Command::Batch(
+                group
+                    .clone()
+                    .map(|index| Command::SetPlacement {
+                        index,
+                        old: loco.placement,
+                        new: placement,
+                    })
+                    .collect(),
+            )
+        });
+        if loco.loco.is_steam() {
+            let mut reversed = loco.reversed;
+            if ui
+                .checkbox(&mut reversed, i18n.tr("loco-reversed"))
+                .changed()
+            {
+                command = Some(Command::Batch(
+                    group
+                        .map(|index| Command::ToggleReversed { index })
+                        .collect(),
+                ));
+            }
+        }
+        ui.label(i18n.tr_args(
+            "loco-details-weight",
+            &[("weight", units.mass(loco.weight))],
+        ));
+        ui.label(i18n.tr_args(
+            "loco-details-length",
+            &[("length", units.length(loco.length))],
+        ));
+        ui.label(i18n.tr_args(
+            "loco-details-speed",
+            &[("speed", loco.max_speed_kmh().to_string())],
+        ));
+        if !loco.has_power {
+            ui.label(i18n.tr("loco-unpowered"));
+            return command;
+        }
+        egui::Grid::new(egui::Id::new("loco_ratings").with(loco.loco))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for rating in RATINGS {
+                    ui.label(i18n.rating(rating));
+                    ui.label(units.mass(Mass::from_tonnes(f32::from(loco.rating(rating)))));
+                    ui.end_row();
+                }
+            });
+        // The share is of what the locomotives themselves can pull in the
+        // chosen conditions, before any coupler limit.
+        let pull = self.conditions.capacity(loco);
+        let total: f32 = self
+            .consist
             .locomotives
             .iter()
-            .fold(0, |a, l| if l.powered { a + l.rain_grade_t } else { a });
-        self.supported_weight_0_deg = weight_0_deg;
-        self.supported_weight_2_deg = weight_2_deg;
-        self.supported_weight_rain = weight_rain;
+            .map(|l| self.conditions.capacity(l))
+            .sum();
+        let share = if total > 0.0 {
+            pull * count as f32 / total
+        } else {
+            0.0
+        };
+        let percent = format!("{:.0}", share * 100.0);
+        ui.horizontal(|ui| {
+            ui.label(i18n.tr_args(
+                "loco-conditions-pull",
+                &[("weight", units.mass(Mass::from_tonnes(pull)))],
+            ));
+            ui.add(
+                egui::ProgressBar::new(share)
+                    .desired_width(70.0)
+                    .text(format!("{percent}%")),
+            )
+            .on_hover_text(i18n.tr_args("loco-contribution", &[("percent", percent)]));
+        });
+        command
+    }
+
+    // Picks where a locomotive is coupled: at the head, behind one of the
+    // cuts on the train, or at the rear.
+    fn placement_combo(&self, ui: &mut egui::Ui, loco: &LocomotiveInfo) -> Option<Placement> {
+        let i18n = &self.i18n;
+        let cuts: Vec<&str> = self
+            .consist
+            .orders_on_train()
+            .map(|o| o.name.as_str())
+            .collect();
+        let label = |placement: Placement| match placement {
+            Placement::Head => i18n.tr("placement-head"),
+            Placement::AfterCut(n) => {
+                let cut = n
+                    .checked_sub(1)
+                    .and_then(|ix| cuts.get(usize::from(ix)))
+                    .map_or_else(|| n.to_string(), |name| (*name).to_owned());
+                i18n.tr_args("placement-after-cut", &[("cut", cut)])
+            }
+            Placement::Rear => i18n.tr("placement-rear"),
+        };
+        let mut picked = loco.placement;
+        egui::ComboBox::new(ui.id().with("placement"), i18n.tr("placement-label"))
+            .selected_text(label(picked))
+            .show_ui(ui, |ui| {
+                let choices = std::iter::once(Placement::Head)
+                    .chain(
+                        (1..=cuts.len())
+                            .filter_map(|n| u16::try_from(n).ok().map(Placement::AfterCut)),
+                    )
+                    .chain(std::iter::once(Placement::Rear));
+                for placement in choices {
+                    ui.selectable_value(&mut picked, placement, label(placement));
+                }
+            });
+        (picked != loco.placement).then_some(picked)
+    }
+
+    fn delete_selected(&mut self) {
+        let command = match self.selection {
+            Some(Selection::Locomotive(index)) => self
+                .consist
+                .locomotive_group(index)
+                .zip(self.consist.locomotives.get(index))
+                .and_then(|(group, loco)| resize_group(loco, group, 0)),
+            Some(Selection::Order(index)) => {
+                self.consist
+                    .orders
+                    .get(index)
+                    .map(|order| Command::DeleteOrder {
+                        index,
+                        order: order.clone(),
+                    })
+            }
+            None => None,
+        };
+        if let Some(command) = command {
+            self.execute(command);
+        }
+    }
+
+    // Moves the selected order one place along the list, or the selected
+    // locomotive's group past its neighbouring group.
+    fn move_selected(&mut self, down: bool) {
+        match self.selection {
+            Some(Selection::Locomotive(ix)) => {
+                let groups = self.consist.locomotive_groups();
+                let Some(position) = groups.iter().position(|g| g.contains(&ix)) else {
+                    return;
+                };
+                let to = if down {
+                    position + 1
+                } else {
+                    position.wrapping_sub(1)
+                };
+                let (Some(from), Some(onto)) = (groups.get(position), groups.get(to)) else {
+                    return;
+                };
+                // The group lands either where the neighbour started, or just after it.
+                let start = if down {
+                    from.start + onto.len()
+                } else {
+                    onto.start
+                };
+                if let Some(command) = move_group(from.clone(), onto.clone()) {
+                    self.execute(command);
+                    self.selection = Some(Selection::Locomotive(start));
+                }
+            }
+            Some(Selection::Order(from)) => {
+                let to = if down { from + 1 } else { from.wrapping_sub(1) };
+                if to < self.consist.orders.len() {
+                    self.execute(Command::MoveOrder { from, to });
+                    self.selection = Some(Selection::Order(to));
+                }
+            }
+            None => {}
+        }
     }
 
-    // Recalculates the total weight and length of the current consist.
-    pub fn recalc_consist(&mut self) {
-        let loco_weight = self.locomotives.iter().fold(0.0, |a, l| a + l.weight);
-        let order_weight = self.orders.iter().fold(0.0, |a, o| a + o.weight);
-        self.total_weight = loco_weight + order_weight;
-        let loco_length = self.locomotives.iter().fold(0.0, |a, l| a + l.length);
-        let order_length = self.orders.iter().fold(0.0, |a, o| a + o.length);
-        self.total_length = loco_length + order_length;
+    // Selects the next or previous locomotive, or order as listed in the table.
+    fn step_selection(&mut self, forward: bool) {
+        self.selection = match self.selection {
+            Some(Selection::Locomotive(ix)) => {
+                let groups = self.consist.locomotive_groups();
+                let position = groups.iter().position(|g| g.contains(&ix)).unwrap_or(0);
+                let next = if forward {
+                    position + 1
+                } else {
+                    position.saturating_sub(1)
+                };
+                groups
+                    .get(next)
+                    .or(groups.get(position))
+                    .map(|g| Selection::Locomotive(g.start))
+            }
+            Some(Selection::Order(ix)) => {
+                let visible = self.visible_orders();
+                let position = visible.iter().position(|v| *v == ix).unwrap_or(0);
+                let next = if forward {
+                    position + 1
+                } else {
+                    position.saturating_sub(1)
+                };
+                visible
+                    .get(next)
+                    .or(visible.get(position))
+                    .map(|ix| Selection::Order(*ix))
+            }
+            None => self
+                .visible_orders()
+                .first()
+                .map(|ix| Selection::Order(*ix)),
+        };
     }
 }
 
@@ -106,81 +784,187 @@ impl eframe::App for ConsistManagerApp {
             .fill(ctx.style().visuals.window_fill())
             .stroke(ctx.style().visuals.window_stroke());
 
+        // The dialogs read and show weights and lengths in the preferred units.
+        self.new_order_modal.units = self.units;
+        self.edit_order_modal.units = self.units;
+        self.paste_jobs_modal.units = self.units;
+
+        self.handle_shortcuts(ctx);
+
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
-                if ui.button("Add Locomotive/Car").clicked() {
-                    self.add_loco_modal_open = true;
+                let menu_buttons = [
+                    ("menu-add-locomotive", Action::AddLocomotive),
+                    ("menu-add-order", Action::AddOrder),
+                    ("menu-paste-jobs", Action::PasteJobs),
+                    ("menu-station-map", Action::StationMap),
+                    ("menu-trip", Action::Trip),
+                    ("menu-suggest-power", Action::SuggestPower),
+                    ("menu-job-board", Action::JobBoard),
+                ];
+                for (key, action) in menu_buttons {
+                    let label = self.i18n.tr(key);
+                    if self.menu_button(ui, &label, action, true).clicked() {
+                        self.run_action(action);
+                    }
+                    ui.add_space(15.0);
+                }
+                // The number of broken rules shows even with the window closed.
+                let broken = check(&self.consist, &self.rules).len();
+                let label = if broken == 0 {
+                    self.i18n.tr("menu-checks")
+                } else {
+                    self.i18n
+                        .tr_args("menu-checks-count", &[("count", broken.to_string())])
+                };
+                if self.menu_button(ui, &label, Action::Checks, true).clicked() {
+                    self.run_action(Action::Checks);
                 }
                 ui.add_space(15.0);
-                if ui.button("Add Order").clicked() {
-                    self.new_order_modal.open = true;
-                    self.order_index = None;
+                let can_undo = self.history.can_undo();
+                let label = self.i18n.tr("menu-undo");
+                if self
+                    .menu_button(ui, &label, Action::Undo, can_undo)
+                    .clicked()
+                {
+                    self.undo();
+                }
+                let can_redo = self.history.can_redo();
+                let label = self.i18n.tr("menu-redo");
+                if self
+                    .menu_button(ui, &label, Action::Redo, can_redo)
+                    .clicked()
+                {
+                    self.redo();
+                }
+                ui.add_space(15.0);
+                let label = self.i18n.tr("menu-shortcuts");
+                if self
+                    .menu_button(ui, &label, Action::Shortcuts, true)
+                    .clicked()
+                {
+                    self.run_action(Action::Shortcuts);
                 }
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
                     egui::widgets::global_theme_preference_buttons(ui);
+                    self.units.picker_ui(ui, &self.i18n);
+                    self.language_picker_ui(ui);
                 });
             });
         });
 
+        egui::TopBottomPanel::top("conditions_bar").show(ctx, |ui| {
+            self.conditions_ui(ui);
+        });
+
         egui::SidePanel::left("loco-menu")
             .frame(frame)
             .show(ctx, |ui| {
-                let mut loco_to_delete = None;
-                ui.vertical_centered(|ui| ui.heading("Current Locomotives"));
-                ui.separator();
-                for ix in 0..self.locomotives.len() {
-                    ui.horizontal(|ui| {
-                        let loco = self
-                            .locomotives
-                            .get_mut(ix)
-                            .expect("Went out of bounds on locomotive list");
-                        let response = ui.label(format!("- {}", loco.loco));
-                        if loco.has_power {
-                            let resp = ui.add(toggle(&mut loco.powered));
-                            if resp.changed() {
-                                self.recalc_loco_limits();
-                            }
-                        }
-                        egui::Popup::context_menu(&response)
-                            .id(egui::Id::new("loco_menu").with(ix))
-                            .close_behavior(egui::PopupCloseBehavior::CloseOnClick)
-                            .show(|ui| {
-                                ui.set_min_width(200.0);
-                                if ui.button("Delete locomotive").clicked() {
-                                    loco_to_delete = Some(ix);
-                                }
-                            });
-                    });
-                }
-                if let Some(loco) = loco_to_delete {
-                    self.locomotives.remove(loco);
-                    self.recalc_loco_limits();
-                    self.recalc_consist();
-                }
+                self.locomotives_ui(ui);
             });
 
         egui::SidePanel::right("status-menu")
             .frame(frame)
             .show(ctx, |ui| {
-                ui.vertical_centered(|ui| ui.heading("Consist Info"));
+                let i18n = &self.i18n;
+                ui.vertical_centered(|ui| ui.heading(i18n.tr("info-heading")));
                 ui.separator();
-                ui.label(format!("- Total Weight: {:.2} T", self.total_weight));
-                ui.label("- Supported Weights:");
-                ui.label(format!("  - 0% grade: {} T", self.supported_weight_0_deg));
-                ui.label(format!("  - 2% grade: {} T", self.supported_weight_2_deg));
-                ui.label(format!(
-                    "  - 2% grade in rain: {} T",
-                    self.supported_weight_rain
+                let summary = &self.summary;
+                let units = &self.units;
+                ui.label(i18n.tr_args(
+                    "info-total-weight",
+                    &[("weight", units.mass(summary.total_weight))],
                 ));
+                if summary.planned_weight > Mass::ZERO {
+                    ui.label(i18n.tr_args(
+                        "info-still-to-pick-up",
+                        &[("weight", units.mass(summary.planned_weight))],
+                    ));
+                }
+                ui.label(i18n.tr_args(
+                    "info-supported-in-conditions",
+                    &[(
+                        "weight",
+                        units.mass(self.consist.supported_weight(&self.conditions)),
+                    )],
+                ));
+                egui::CollapsingHeader::new(i18n.tr("info-supported-weights"))
+                    .id_salt("rated_weights")
+                    .show(ui, |ui| {
+                        for rating in RATINGS {
+                            let supported =
+                                Mass::from_tonnes(f32::from(summary.supported_weights.get(rating)));
+                            ui.label(i18n.tr_args(
+                                "info-supported-weight",
+                                &[
+                                    ("rating", i18n.rating(rating)),
+                                    ("weight", units.mass(supported)),
+                                ],
+                            ));
+                        }
+                    });
                 ui.separator();
-                ui.label(format!("- Total Length: {:.2}m", self.total_length));
+                ui.label(i18n.tr_args(
+                    "info-total-length",
+                    &[("length", units.length(summary.total_length))],
+                ));
+                match summary.power_positions {
+                    0 => {}
+                    1 => {
+                        ui.label(i18n.tr("info-power-single"));
+                    }
+                    positions => {
+                        ui.label(i18n.tr_args(
+                            "info-power-distributed",
+                            &[("positions", positions.to_string())],
+                        ));
+                    }
+                }
+                if let Some(limit) = &summary.max_speed {
+                    ui.label(i18n.tr_args(
+                        "info-max-speed",
+                        &[
+                            ("speed", limit.kmh.to_string()),
+                            ("limiter", i18n.speed_limiter(&limit.limiter)),
+                        ],
+                    ));
+                }
+                // Whether the train is too heavy is shown for the chosen
+                // conditions in the conditions bar rather than per rating.
+                let warnings: Vec<&ConsistWarning> = summary
+                    .warnings
+                    .iter()
+                    .filter(|w| !matches!(w, ConsistWarning::Overweight { .. }))
+                    .collect();
+                let stall = legs(&self.consist, &self.conditions)
+                    .into_iter()
+                    .find(Leg::stalls);
+                if !warnings.is_empty() || stall.is_some() {
+                    ui.separator();
+                    for warning in warnings {
+                        let text = format!("- {}", i18n.warning(warning, units));
+                        ui.colored_label(ui.visuals().warn_fg_color, text);
+                    }
+                    if let Some(leg) = stall {
+                        let text = i18n.tr_args(
+                            "warning-stall",
+                            &[
+                                ("from", i18n.station(leg.from)),
+                                ("to", i18n.station(leg.to)),
+                                ("grade", format!("{:.1}", leg.ruling_grade)),
+                            ],
+                        );
+                        ui.colored_label(ui.visuals().error_fg_color, format!("- {text}"))
+                            .on_hover_text(i18n.tr("grades-provisional"));
+                    }
+                }
             });
 
         if self.add_loco_modal_open {
             let modal = egui::Modal::new("Add Locomotive/Car".into()).show(ctx, |ui| {
                 ui.set_width(250.0);
-                ui.heading("Add Locomotive/Car");
-                egui::ComboBox::from_label("Locomotive/Car")
+                ui.heading(self.i18n.tr("add-loco-heading"));
+                egui::ComboBox::from_label(self.i18n.tr("add-loco-label"))
                     .selected_text(self.selected_loco.loco.to_string())
                     .show_ui(ui, |ui| {
                         for l in LOCO_LIST {
@@ -200,13 +984,14 @@ impl eframe::App for ConsistManagerApp {
                     ui,
                     |_ui| {},
                     |ui| {
-                        if ui.button("Add").clicked() {
-                            self.locomotives.push(self.selected_loco.clone());
-                            self.recalc_consist();
-                            self.recalc_loco_limits();
+                        if ui.button(self.i18n.tr("button-add")).clicked() {
+                            self.execute(Command::AddLocomotive {
+                                index: self.consist.locomotives.len(),
+                                loco: self.selected_loco.clone(),
+                            });
                             ui.close();
                         }
-                        if ui.button("Cancel").clicked() {
+                        if ui.button(self.i18n.tr("button-cancel")).clicked() {
                             ui.close();
                         }
                     },
@@ -219,130 +1004,402 @@ impl eframe::App for ConsistManagerApp {
         }
 
         egui::CentralPanel::default().frame(frame).show(ctx, |ui| {
-            ui.vertical_centered(|ui| ui.heading("Orders"));
+            ui.vertical_centered(|ui| ui.heading(self.i18n.tr("orders-heading")));
+            ui.separator();
+            ui.add(consist_strip(&self.consist, &self.i18n, &self.units));
             ui.separator();
+            self.order_filter.controls_ui(ui, &self.i18n);
+            self.delivered_ui(ui);
+            let visible = self.visible_orders();
+            if self.order_filter.is_filtering() {
+                let open_orders = self
+                    .consist
+                    .orders
+                    .iter()
+                    .filter(|o| o.status != OrderStatus::Delivered)
+                    .count();
+                let (weight, length) = visible
+                    .iter()
+                    .filter_map(|ix| self.consist.orders.get(*ix))
+                    .fold((Mass::ZERO, Length::ZERO), |(w, l), o| {
+                        (w + o.weight, l + o.length)
+                    });
+                ui.label(self.i18n.tr_args(
+                    "orders-showing",
+                    &[
+                        ("shown", visible.len().to_string()),
+                        ("total", open_orders.to_string()),
+                        ("weight", self.units.mass(weight)),
+                        ("length", self.units.length(length)),
+                    ],
+                ));
+            }
+            // Dragging rows around only makes sense in the consist's own order.
+            let reorderable = !self.order_filter.is_sorted();
+            let violations = check(&self.consist, &self.rules);
+            let painter = ui.painter().clone();
             TableBuilder::new(ui)
                 .striped(true)
                 .sense(egui::Sense::click())
-                .columns(Column::auto().resizable(false), 7)
+                .columns(Column::auto().resizable(false), 9)
                 .header(20.0, |mut header| {
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Order Name").strong());
-                    });
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Weight").strong());
-                    });
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Length").strong());
-                    });
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Pickup Station").strong());
-                    });
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Pickup Track").strong());
-                    });
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Dropoff Station").strong());
-                    });
-                    header.col(|ui| {
-                        ui.label(egui::RichText::new("Dropoff Track").strong());
-                    });
+                    header.col(|_ui| {});
+                    for (column, key) in [
+                        (SortColumn::Name, "column-order-name"),
+                        (SortColumn::Weight, "column-weight"),
+                        (SortColumn::Length, "column-length"),
+                        (SortColumn::PickupStation, "column-pickup-station"),
+                        (SortColumn::PickupTrack, "column-pickup-track"),
+                        (SortColumn::DropoffStation, "column-dropoff-station"),
+                        (SortColumn::DropoffTrack, "column-dropoff-track"),
+                        (SortColumn::Status, "column-status"),
+                    ] {
+                        header.col(|ui| {
+                            let label = self.i18n.tr(key);
+                            self.order_filter.header_ui(ui, &self.i18n, column, &label);
+                        });
+                    }
                 })
                 .body(|body| {
-                    let mut order_to_delete = None;
+                    let mut command = None;
 
-                    body.rows(30.0, self.orders.len(), |mut row| {
-                        let order = self.orders.get(row.index()).expect("Indexing woes").clone();
+                    body.rows(30.0, visible.len(), |mut row| {
+                        let row_ix = *visible.get(row.index()).expect("Indexing woes");
+                        let order = self
+                            .consist
+                            .orders
+                            .get(row_ix)
+                            .expect("Indexing woes")
+                            .clone();
                         row.set_overline(true);
+                        row.set_selected(self.selection == Some(Selection::Order(row_ix)));
                         row.col(|ui| {
-                            ui.label(&order.name);
+                            if reorderable {
+                                ui.dnd_drag_source(
+                                    egui::Id::new("order_drag").with(row_ix),
+                                    OrderDrag(row_ix),
+                                    |ui| ui.label("☰"),
+                                );
+                            }
+                        });
+                        row.col(|ui| {
+                            // Orders breaking a rule are marked in the colour of
+                            // the most severe one, with the details on hover.
+                            let broken: Vec<_> = violations
+                                .iter()
+                                .filter(|v| v.orders.contains(&row_ix))
+                                .collect();
+                            match broken.first() {
+                                Some(worst) => {
+                                    let color = severity_color(ui, worst.severity);
+                                    let details: Vec<String> = broken
+                                        .iter()
+                                        .map(|v| self.i18n.violation(v, &self.consist.orders))
+                                        .collect();
+                                    ui.colored_label(color, format!("⚠ {}", order.name))
+                                        .on_hover_text(details.join("\n"));
+                                }
+                                None => {
+                                    ui.label(&order.name);
+                                }
+                            }
                         });
                         row.col(|ui| {
-                            ui.label(order.weight.to_string());
+                            ui.label(self.units.mass(order.weight));
                         });
                         row.col(|ui| {
-                            ui.label(order.length.to_string());
+                            ui.label(self.units.length(order.length));
                         });
                         row.col(|ui| {
-                            ui.label(order.pickup_station.to_abbrev());
+                            ui.label(order.pickup_station.to_abbrev())
+                                .on_hover_text(self.i18n.station(order.pickup_station));
                         });
                         row.col(|ui| {
                             ui.label(&order.pickup_track);
                         });
                         row.col(|ui| {
-                            ui.label(order.dropoff_station.to_abbrev());
+                            ui.label(order.dropoff_station.to_abbrev())
+                                .on_hover_text(self.i18n.station(order.dropoff_station));
                         });
                         row.col(|ui| {
                             ui.label(&order.dropoff_track);
                         });
+                        row.col(|ui| {
+                            let id = egui::Id::new("order_status").with(row_ix);
+                            if let Some(new) = status_combo(ui, &self.i18n, id, order.status) {
+                                command = Some(Command::SetOrderStatus {
+                                    index: row_ix,
+                                    old: order.status,
+                                    new,
+                                });
+                            }
+                        });
 
-                        egui::Popup::context_menu(&row.response())
-                            .id(egui::Id::new("order_menu").with(row.index()))
+                        let response = row.response();
+                        if response.clicked() {
+                            self.selection = Some(Selection::Order(row_ix));
+                        }
+                        if let Some(dragged) = response.dnd_hover_payload::<OrderDrag>() {
+                            paint_drop_marker(&painter, response.rect, dragged.0, row_ix);
+                        }
+                        if let Some(dragged) = response.dnd_release_payload::<OrderDrag>()
+                            && dragged.0 != row_ix
+                        {
+                            command = Some(Command::MoveOrder {
+                                from: dragged.0,
+                                to: row_ix,
+                            });
+                        }
+
+                        egui::Popup::context_menu(&response)
+                            .id(egui::Id::new("order_menu").with(row_ix))
                             .close_behavior(egui::PopupCloseBehavior::CloseOnClick)
                             .show(|ui| {
-                                let row_ix = row.index();
                                 ui.set_min_width(200.0);
-                                ui.menu_button("Add order...", |ui| {
-                                    if ui.button("Above").clicked() {
+                                ui.menu_button(self.i18n.tr("order-menu-add"), |ui| {
+                                    if ui.button(self.i18n.tr("order-menu-above")).clicked() {
                                         self.new_order_modal.open = true;
                                         if row_ix == 0 {
                                             self.order_index = Some(0);
                                         } else {
-                                            self.order_index = Some(row.index() - 1);
+                                            self.order_index = Some(row_ix - 1);
                                         }
-                                    } else if ui.button("Below").clicked() {
+                                    } else if ui.button(self.i18n.tr("order-menu-below")).clicked()
+                                    {
                                         self.new_order_modal.open = true;
-                                        if row_ix == self.orders.len() {
+                                        if row_ix == self.consist.orders.len() {
                                             self.order_index = None;
                                         } else {
-                                            self.order_index = Some(row.index() + 1);
+                                            self.order_index = Some(row_ix + 1);
                                         }
                                     }
                                 });
-                                ui.menu_button("Move order...", |ui| {
-                                    if ui.button("Up").clicked() {
+                                ui.menu_button(self.i18n.tr("order-menu-move"), |ui| {
+                                    if ui.button(self.i18n.tr("order-menu-up")).clicked() {
                                         if row_ix > 0 {
-                                            self.orders.swap(row_ix, row_ix - 1);
+                                            command = Some(Command::MoveOrder {
+                                                from: row_ix,
+                                                to: row_ix - 1,
+                                            });
                                         }
-                                    } else if ui.button("Down").clicked()
-                                        && row_ix < self.orders.len() - 1
+                                    } else if ui.button(self.i18n.tr("order-menu-down")).clicked()
+                                        && row_ix < self.consist.orders.len() - 1
                                     {
-                                        self.orders.swap(row_ix, row_ix + 1);
+                                        command = Some(Command::MoveOrder {
+                                            from: row_ix,
+                                            to: row_ix + 1,
+                                        });
                                     }
                                 });
-                                if ui.button("Edit order").clicked() {
-                                    self.edit_order_modal.init_from_order(&order, row.index());
+                                if ui.button(self.i18n.tr("order-menu-edit")).clicked() {
+                                    self.edit_order_modal.init_from_order(&order, row_ix);
                                     self.edit_order_modal.open = true;
-                                } else if ui.button("Delete order").clicked() {
-                                    order_to_delete = Some(row.index());
+                                } else if ui.button(self.i18n.tr("order-menu-to-board")).clicked() {
+                                    command = Some(return_job(&self.consist, row_ix));
+                                } else if ui.button(self.i18n.tr("order-menu-delete")).clicked() {
+                                    command = Some(Command::DeleteOrder {
+                                        index: row_ix,
+                                        order: order.clone(),
+                                    });
                                 }
                             });
                     });
-                    if let Some(index) = order_to_delete {
-                        self.orders.remove(index);
-                        self.recalc_consist();
+                    if let Some(command) = command {
+                        self.execute(command);
                     }
                 });
         });
 
-        self.new_order_modal.show(ctx);
-        if let Some(order) = &self.new_order_modal.order {
-            match self.order_index {
-                Some(ix) => self.orders.insert(ix, order.clone()),
-                None => self.orders.push(order.clone()),
-            }
-            self.recalc_consist();
-            self.new_order_modal.order = None;
+        self.new_order_modal.show(ctx, &self.i18n);
+        if let Some(order) = self.new_order_modal.order.take() {
+            let index = self.order_index.map_or(self.consist.orders.len(), |ix| {
+                ix.min(self.consist.orders.len())
+            });
+            self.execute(Command::AddOrder { index, order });
+        }
+
+        self.station_map.show(
+            ctx,
+            &self.consist,
+            &self.conditions,
+            self.order_filter.station,
+            &self.i18n,
+        );
+        if let Some(station) = self.station_map.clicked.take() {
+            // Clicking the station being filtered on again clears the filter.
+            let filter = &mut self.order_filter.station;
+            *filter = (*filter != Some(station)).then_some(station);
+        }
+
+        self.shortcuts_window
+            .show(ctx, &mut self.keymap, &self.i18n);
+        self.checks_window
+            .show(ctx, &self.consist, &mut self.rules, &self.i18n);
+        if let Some(command) = self.checks_window.command.take() {
+            self.execute(command);
+        }
+        self.power_window.show(
+            ctx,
+            &self.consist,
+            &self.conditions,
+            &mut self.roster,
+            &self.i18n,
+            &self.units,
+        );
+        if let Some(command) = self.power_window.command.take() {
+            self.execute(command);
+        }
+        self.job_board.show(
+            ctx,
+            &self.consist,
+            &self.conditions,
+            &mut self.length_limits,
+            &self.i18n,
+            &self.units,
+        );
+        if let Some(command) = self.job_board.command.take() {
+            self.execute(command);
+        }
+
+        self.palette.show(ctx, &self.keymap, &self.i18n);
+        match self.palette.chosen.take() {
+            Some(PaletteEntry::Action(action)) => self.run_action(action),
+            Some(PaletteEntry::Station(station)) => self.order_filter.station = Some(station),
+            None => {}
+        }
+
+        self.trip.show(
+            ctx,
+            &self.consist,
+            &self.conditions,
+            &self.i18n,
+            &self.units,
+        );
+        if let Some(command) = self.trip.command.take() {
+            self.execute(command);
+        }
+
+        self.paste_jobs_modal.show(ctx, &self.i18n);
+        if let Some(orders) = self.paste_jobs_modal.orders.take() {
+            self.append_orders(orders);
+        }
+        if let Some(jobs) = self.paste_jobs_modal.jobs.take() {
+            self.append_jobs(jobs);
         }
 
-        self.edit_order_modal.show(ctx);
-        if let Some(edited_order) = &self.edit_order_modal.order {
-            let ix = self.edit_order_modal.index;
-            if let Some(order) = self.orders.get_mut(ix) {
-                *order = edited_order.clone();
-                self.recalc_consist();
-                self.edit_order_modal.order = None;
+        self.edit_order_modal.show(ctx, &self.i18n);
+        if let Some(edited_order) = self.edit_order_modal.order.take() {
+            let index = self.edit_order_modal.index;
+            if let Some(order) = self.consist.orders.get(index) {
+                self.execute(Command::EditOrder {
+                    index,
+                    old: order.clone(),
+                    new: edited_order,
+                });
             }
         }
     }
 }
+
+fn weather_label(i18n: &I18n, weather: Weather) -> String {
+    i18n.tr(match weather {
+        Weather::Dry => "weather-dry",
+        Weather::Rain => "weather-rain",
+        Weather::Snow => "weather-snow",
+        Weather::Leaves => "weather-leaves",
+    })
+}
+
+/// The locomotive or order that keyboard actions apply to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    Locomotive(usize),
+    Order(usize),
+}
+
+/// The most identical units a locomotive entry's quantity can be set to.
+const MAX_IDENTICAL_LOCOMOTIVES: usize = 20;
+
+// Moves an order to the end of the job board, as a job not yet taken.
+fn return_job(consist: &Consist, index: usize) -> Command {
+    let mut commands = Vec::new();
+    if let Some(order) = consist.orders.get(index)
+        && order.status != OrderStatus::Planned
+    {
+        commands.push(Command::SetOrderStatus {
+            index,
+            old: order.status,
+            new: OrderStatus::Planned,
+        });
+    }
+    commands.push(Command::ReturnJob {
+        order: index,
+        job: consist.jobs.len(),
+    });
+    Command::Batch(commands)
+}
+
+/// Drag-and-drop payload for reordering locomotives, holding the index of the
+/// first unit of the dragged group.
+struct LocoDrag(usize);
+
+// Adds or removes units at the end of a group of identical locomotives so
+// that it has `quantity` units. A quantity of 0 deletes the group.
+fn resize_group(loco: &LocomotiveInfo, group: Range<usize>, quantity: usize) -> Option<Command> {
+    let count = group.len();
+    let commands: Vec<Command> = if quantity > count {
+        (group.end..group.start + quantity)
+            .map(|index| Command::AddLocomotive {
+                index,
+                loco: loco.clone(),
+            })
+            .collect()
+    } else {
+        // Each deletion shifts the rest of the group down into the same index.
+        (quantity..count)
+            .map(|_| Command::DeleteLocomotive {
+                index: group.start + quantity,
+                loco: loco.clone(),
+            })
+            .collect()
+    };
+    (!commands.is_empty()).then_some(Command::Batch(commands))
+}
+
+// Moves a group of locomotives past the group it is dropped onto, keeping
+// the order of its units.
+fn move_group(from: Range<usize>, onto: Range<usize>) -> Option<Command> {
+    let commands: Vec<Command> = if from.start < onto.start {
+        // Each move pulls the next unit of the group to just before the end
+        // of the group it is dropped onto.
+        from.clone()
+            .map(|_| Command::MoveLocomotive {
+                from: from.start,
+                to: onto.end - 1,
+            })
+            .collect()
+    } else if from.start > onto.start {
+        (0..from.len())
+            .map(|i| Command::MoveLocomotive {
+                from: from.start + i,
+                to: onto.start + i,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    (!commands.is_empty()).then_some(Command::Batch(commands))
+}
+
+/// Drag-and-drop payload for reordering orders.
+struct OrderDrag(usize);
+
+// Draws a line where a dragged row will land if it is dropped onto row `to`.
+fn paint_drop_marker(painter: &egui::Painter, rect: egui::Rect, from: usize, to: usize) {
+    if from == to {
+        return;
+    }
+    let y = if from < to { rect.bottom() } else { rect.top() };
+    let stroke = painter.ctx().style().visuals.selection.stroke;
+    painter.hline(rect.x_range(), y, stroke);
+}
@@ -0,0 +1,182 @@
+//! Prints the weight, length and supported tonnage of a consist file.
+//!
+//! A consist file is JSON with a list of locomotives (either a name such as
+//! `"DE2"` or an object like `{ "loco": "DE6", "powered": false, "placement":
+//! "Rear", "reversed": true }`) and a list of orders in the same format the app stores them in:
+//!
+//! ``` text
+//! { "locomotives": ["DE6", "DE6Slug"], "orders": [ ... ] }
+//! ```
+
+use std::io::Write as _;
+use std::process::ExitCode;
+
+use dv_helper::consist::{Consist, ConsistSummary, RATINGS};
+use dv_helper::locomotive::{Locomotive, Placement, locomotives};
+use dv_helper::order::Order;
+use dv_helper::units::{LengthUnit, Mass, MassUnit, Units};
+
+const USAGE: &str = "Usage: dv_helper-cli [--json] [--short-tons] [--feet] [--coupler-limit <weight>] <consist-file>";
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum LocoEntry {
+    Name(Locomotive),
+    Entry {
+        loco: Locomotive,
+        #[serde(default = "default_powered")]
+        powered: bool,
+        placement: Option<Placement>,
+        #[serde(default)]
+        reversed: bool,
+    },
+}
+
+fn default_powered() -> bool {
+    true
+}
+
+#[derive(serde::Deserialize)]
+struct ConsistFile {
+    #[serde(default)]
+    locomotives: Vec<LocoEntry>,
+    #[serde(default)]
+    orders: Vec<Order>,
+}
+
+fn write_json(summary: &ConsistSummary, out: &mut impl std::io::Write) -> std::io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, summary)?;
+    writeln!(out)
+}
+
+fn write_text(
+    summary: &ConsistSummary,
+    units: &Units,
+    out: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    writeln!(out, "Total Weight: {}", units.mass(summary.total_weight))?;
+    if summary.planned_weight > Mass::ZERO {
+        writeln!(
+            out,
+            "Still to pick up: {}",
+            units.mass(summary.planned_weight)
+        )?;
+    }
+    writeln!(out, "Total Length: {}", units.length(summary.total_length))?;
+    writeln!(out, "Supported Weights:")?;
+    for rating in RATINGS {
+        let supported = Mass::from_tonnes(f32::from(summary.supported_weights.get(rating)));
+        writeln!(out, "  {rating}: {}", units.mass(supported))?;
+    }
+    if summary.power_positions > 1 {
+        writeln!(
+            out,
+            "Distributed power in {} places",
+            summary.power_positions
+        )?;
+    }
+    if let Some(limit) = &summary.max_speed {
+        writeln!(out, "Max Speed: {} km/h ({})", limit.kmh, limit.limiter)?;
+    }
+    if !summary.warnings.is_empty() {
+        writeln!(out, "Warnings:")?;
+        for warning in &summary.warnings {
+            writeln!(out, "  - {}", warning.describe(units))?;
+        }
+    }
+    Ok(())
+}
+
+fn load_consist(path: &str) -> Result<Consist, String> {
+    let data = std::fs::read(path).map_err(|err| format!("Could not read {path}: {err}"))?;
+    let file: ConsistFile = serde_json::from_slice(&data)
+        .map_err(|err| format!("{path} is not a valid consist file: {err}"))?;
+    let locomotives = file
+        .locomotives
+        .into_iter()
+        .map(|entry| {
+            let (loco, powered, placement, reversed) = match entry {
+                LocoEntry::Name(loco) => (loco, true, None, false),
+                LocoEntry::Entry {
+                    loco,
+                    powered,
+                    placement,
+                    reversed,
+                } => (loco, powered, placement, reversed),
+            };
+            let mut info = locomotives()
+                .get(&loco)
+                .expect("Unknown locomotive/car")
+                .clone();
+            info.powered = info.has_power && powered;
+            info.placement = placement.unwrap_or(info.placement);
+            info.reversed = reversed;
+            info
+        })
+        .collect();
+    Ok(Consist {
+        locomotives,
+        orders: file.orders,
+        jobs: Vec::new(),
+    })
+}
+
+fn run(json: bool, units: &Units, coupler_limit: Option<Mass>, path: &str) -> Result<(), String> {
+    let summary = load_consist(path)?.summary(coupler_limit);
+    let mut out = std::io::stdout().lock();
+    let written = if json {
+        write_json(&summary, &mut out)
+    } else {
+        write_text(&summary, units, &mut out)
+    };
+    written.map_err(|err| format!("Could not write the report: {err}"))
+}
+
+fn main() -> ExitCode {
+    let mut json = false;
+    let mut units = Units::default();
+    let mut coupler_limit = None;
+    let mut path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--short-tons" => units.mass = MassUnit::ShortTons,
+            "--feet" => units.length = LengthUnit::Feet,
+            "--coupler-limit" => {
+                let Some(limit) = args
+                    .next()
+                    .and_then(|v| v.parse::<f32>().ok())
+                    .filter(|limit| *limit >= 0.0)
+                else {
+                    writeln!(std::io::stderr(), "--coupler-limit needs a weight\n{USAGE}").ok();
+                    return ExitCode::from(2);
+                };
+                coupler_limit = Some(limit);
+            }
+            "-h" | "--help" => {
+                writeln!(std::io::stdout(), "{USAGE}").ok();
+                return ExitCode::SUCCESS;
+            }
+            _ if path.is_none() && !arg.starts_with('-') => path = Some(arg),
+            _ => {
+                writeln!(std::io::stderr(), "Unexpected argument {arg}\n{USAGE}").ok();
+                return ExitCode::from(2);
+            }
+        }
+    }
+    let Some(path) = path else {
+        writeln!(std::io::stderr(), "{USAGE}").ok();
+        return ExitCode::from(2);
+    };
+
+    // The limit is given in the same unit weights are printed in.
+    let coupler_limit = coupler_limit.map(|limit| Mass::from_unit(limit, units.mass));
+    match run(json, &units, coupler_limit, &path) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            writeln!(std::io::stderr(), "{err}").ok();
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::units::{Length, Mass};
+
+/// Approximate figures for a single freight car, as seen in the game.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CarInfo {
+    pub empty_weight: Mass,
+    pub length: Length,
+    pub cargo_capacity: Mass,
+    /// The fastest the car may run, in km/h.
+    pub max_speed_kmh: u16,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, serde::Deserialize, serde::Serialize)]
+pub enum CarType {
+    Flatbed,
+    Stake,
+    Boxcar,
+    Refrigerator,
+    Hopper,
+    Gondola,
+    OilTanker,
+    GasTanker,
+    ChemicalTanker,
+    Autorack,
+    Passenger,
+}
+
+impl Display for CarType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let car_str = match self {
+            Self::Flatbed => "Flatbed",
+            Self::Stake => "Stake Flatbed",
+            Self::Boxcar => "Boxcar",
+            Self::Refrigerator => "Refrigerator",
+            Self::Hopper => "Hopper",
+            Self::Gondola => "Gondola",
+            Self::OilTanker => "Oil Tanker",
+            Self::GasTanker => "Gas Tanker",
+            Self::ChemicalTanker => "Chemical Tanker",
+            Self::Autorack => "Autorack",
+            Self::Passenger => "Passenger",
+        };
+        write!(f, "{car_str}")
+    }
+}
+
+impl CarType {
+    pub fn info(self) -> CarInfo {
+        let (empty_weight, length, cargo_capacity, max_speed_kmh) = match self {
+            Self::Flatbed => (12.0, 14.3, 40.0, 120),
+            Self::Stake => (14.0, 14.3, 40.0, 120),
+            Self::Boxcar => (22.0, 14.8, 40.0, 120),
+            Self::Refrigerator => (28.0, 14.8, 35.0, 120),
+            Self::Hopper => (23.0, 13.9, 70.0, 100),
+            Self::Gondola => (22.0, 13.9, 60.0, 100),
+            Self::OilTanker => (25.0, 14.3, 60.0, 100),
+            Self::GasTanker => (28.0, 16.8, 30.0, 80),
+            Self::ChemicalTanker => (25.0, 12.8, 50.0, 80),
+            Self::Autorack => (30.0, 26.0, 20.0, 120),
+            Self::Passenger => (40.0, 24.5, 0.0, 160),
+        };
+        CarInfo {
+            empty_weight: Mass::from_tonnes(empty_weight),
+            length: Length::from_meters(length),
+            cargo_capacity: Mass::from_tonnes(cargo_capacity),
+            max_speed_kmh,
+        }
+    }
+
+    /// Maps the car type names used in savegames (which include the paint
+    /// scheme, e.g. `HopperBrown`) onto a car type.
+    pub fn from_save_name(name: &str) -> Option<Self> {
+        // Order matters: `FlatbedStakes` has to be checked before `Flatbed`.
+        const PREFIXES: [(&str, CarType); 11] = [
+            ("FlatbedStakes", CarType::Stake),
+            ("Flatbed", CarType::Flatbed),
+            ("Boxcar", CarType::Boxcar),
+            ("Refrigerator", CarType::Refrigerator),
+            ("Hopper", CarType::Hopper),
+            ("Gondola", CarType::Gondola),
+            ("TankOil", CarType::OilTanker),
+            ("TankGas", CarType::GasTanker),
+            ("TankChem", CarType::ChemicalTanker),
+            ("Autorack", CarType::Autorack),
+            ("Passenger", CarType::Passenger),
+        ];
+        PREFIXES
+            .iter()
+            .find(|(prefix, _)| name.starts_with(prefix))
+            .map(|(_, car_type)| *car_type)
+    }
+}
+
+pub const CAR_TYPES: [CarType; 11] = [
+    CarType::Flatbed,
+    CarType::Stake,
+    CarType::Boxcar,
+    CarType::Refrigerator,
+    CarType::Hopper,
+    CarType::Gondola,
+    CarType::OilTanker,
+    CarType::GasTanker,
+    CarType::ChemicalTanker,
+    CarType::Autorack,
+    CarType::Passenger,
+];
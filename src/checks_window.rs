@@ -0,0 +1,128 @@
+//! A window listing the rules the consist breaks, with the settings of
+//! each rule and a safe order for hazmat cuts.
+
+use crate::consist::Consist;
+use crate::hazmat::safe_order;
+use crate::history::Command;
+use crate::i18n::I18n;
+use crate::order::OrderStatus;
+use crate::rules::{RULES, Rule, RuleConfig, SEVERITIES, Severity, check};
+
+#[derive(Default)]
+pub struct ChecksWindow {
+    pub open: bool,
+    /// A reordering of the cuts the user chose to apply.
+    pub command: Option<Command>,
+}
+
+impl ChecksWindow {
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        consist: &Consist,
+        config: &mut RuleConfig,
+        i18n: &I18n,
+    ) {
+        let mut open = self.open;
+        egui::Window::new(i18n.tr("checks-heading"))
+            .id(egui::Id::new("checks_window"))
+            .open(&mut open)
+            .default_width(350.0)
+            .show(ctx, |ui| {
+                let violations = check(consist, config);
+                if violations.is_empty() {
+                    ui.label(i18n.tr("checks-passed"));
+                }
+                for violation in &violations {
+                    let color = severity_color(ui, violation.severity);
+                    ui.horizontal(|ui| {
+                        ui.colored_label(color, format!("[{}]", i18n.severity(violation.severity)));
+                        ui.label(i18n.violation(violation, &consist.orders));
+                    });
+                }
+                let hazmat_broken = violations.iter().any(|v| {
+                    matches!(
+                        v.rule,
+                        Rule::HazmatNextToLocomotive | Rule::HazmatSeparation
+                    )
+                });
+                if hazmat_broken {
+                    ui.separator();
+                    self.suggestion_ui(ui, consist, i18n);
+                }
+                ui.separator();
+                egui::CollapsingHeader::new(i18n.tr("checks-rules"))
+                    .id_salt("rule_settings")
+                    .show(ui, |ui| {
+                        egui::Grid::new("rule_settings_grid")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                for rule in RULES {
+                                    let mut setting = config.get(rule);
+                                    ui.checkbox(&mut setting.enabled, i18n.rule(rule));
+                                    egui::ComboBox::from_id_salt(("rule_severity", rule))
+                                        .selected_text(i18n.severity(setting.severity))
+                                        .show_ui(ui, |ui| {
+                                            for severity in SEVERITIES {
+                                                ui.selectable_value(
+                                                    &mut setting.severity,
+                                                    severity,
+                                                    i18n.severity(severity),
+                                                );
+                                            }
+                                        });
+                                    if setting != config.get(rule) {
+                                        config.set(rule, setting);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+        self.open = open;
+    }
+
+    // The cuts in a safe order, with a button to reorder the train.
+    fn suggestion_ui(&mut self, ui: &mut egui::Ui, consist: &Consist, i18n: &I18n) {
+        let Some(order) = safe_order(consist) else {
+            ui.label(i18n.tr("checks-no-safe-order"));
+            return;
+        };
+        let cuts: Vec<&str> = order
+            .iter()
+            .filter_map(|ix| consist.orders.get(*ix))
+            .filter(|o| o.status == OrderStatus::PickedUp)
+            .map(|o| o.name.as_str())
+            .collect();
+        ui.label(i18n.tr_args("checks-suggestion", &[("cuts", cuts.join(" → "))]));
+        if ui.button(i18n.tr("checks-apply")).clicked() {
+            self.command = Some(reorder(&order));
+        }
+    }
+}
+
+// Moves the orders one at a time so that they end up in the given order,
+// undone as one step.
+fn reorder(order: &[usize]) -> Command {
+    let mut current: Vec<usize> = (0..order.len()).collect();
+    let mut moves = Vec::new();
+    for (to, wanted) in order.iter().enumerate() {
+        if let Some(from) = current.iter().position(|ix| ix == wanted)
+            && from != to
+        {
+            let ix = current.remove(from);
+            current.insert(to, ix);
+            moves.push(Command::MoveOrder { from, to });
+        }
+    }
+    Command::Batch(moves)
+}
+
+pub fn severity_color(ui: &egui::Ui, severity: Severity) -> egui::Color32 {
+    let visuals = ui.visuals();
+    match severity {
+        Severity::Info => visuals.text_color(),
+        Severity::Warning => visuals.warn_fg_color,
+        Severity::Error => visuals.error_fg_color,
+    }
+}
@@ -0,0 +1,151 @@
+//! The grade and weather the train has to work in, and what the locomotives
+//! can pull in them.
+//!
+//! Locomotives are only rated on the level, on a 2% grade and on a 2% grade in
+//! the rain. A locomotive's pull is limited by its tractive effort, shared
+//! between rolling resistance and the grade, so the two dry ratings are enough
+//! to work out both and find the rating on any other grade. Weather then
+//! scales the tractive effort by how much grip the wheels have left.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::locomotive::LocomotiveInfo;
+use crate::units::Mass;
+
+/// The steepest grade the conditions bar offers, in percent.
+pub const MAX_GRADE: f32 = 4.0;
+
+/// The coupler limit offered when the player turns the limit on.
+///
+/// This is an estimate, not a figure taken from the game: it's about what a
+/// train of loaded cars gets to before couplers start snapping. Since it
+/// isn't sourced, no limit applies unless the player turns it on.
+pub const DEFAULT_COUPLER_LIMIT: Mass = Mass::from_tonnes(4000.0);
+
+/// How much of the grip lost to the weather sanding wins back.
+const SAND_RECOVERY: f32 = 0.5;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum Weather {
+    #[default]
+    Dry,
+    Rain,
+    Snow,
+    Leaves,
+}
+
+impl Display for Weather {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let weather_str = match self {
+            Self::Dry => "Dry",
+            Self::Rain => "Rain",
+            Self::Snow => "Snow",
+            Self::Leaves => "Leaves on the line",
+        };
+        write!(f, "{weather_str}")
+    }
+}
+
+impl Weather {
+    /// The share of a locomotive's dry tractive effort it keeps in this weather.
+    pub fn adhesion(self, loco: &LocomotiveInfo) -> f32 {
+        match self {
+            Self::Dry => 1.0,
+            // The rain rating is on the same grade as the 2% rating, so the
+            // locomotive's own data says how much grip it loses.
+            Self::Rain if loco.two_grade_t > 0 => {
+                f32::from(loco.rain_grade_t) / f32::from(loco.two_grade_t)
+            }
+            Self::Rain => 0.8,
+            Self::Snow => 0.65,
+            Self::Leaves => 0.5,
+        }
+    }
+}
+
+pub const WEATHERS: [Weather; 4] = [Weather::Dry, Weather::Rain, Weather::Snow, Weather::Leaves];
+
+/// The grade and weather the train is running in.
+#[derive(Clone, Copy, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Conditions {
+    /// The ruling grade, in percent.
+    pub grade: f32,
+    pub weather: Weather,
+    pub sanding: bool,
+    /// The most one coupler can take, if the player has set a limit. Power
+    /// in one place can't move more than this, however strong it is.
+    // Saved under a new name, since the limit used to always be on: the old
+    // value is dropped rather than read as a limit the player chose.
+    #[serde(rename = "chosen_coupler_limit")]
+    pub coupler_limit: Option<Mass>,
+}
+
+impl Default for Conditions {
+    fn default() -> Self {
+        Self {
+            grade: 2.0,
+            weather: Weather::Dry,
+            sanding: false,
+            coupler_limit: None,
+        }
+    }
+}
+
+impl Conditions {
+    /// The tonnes a locomotive can pull in these conditions, or 0 if it isn't
+    /// powered.
+    pub fn capacity(&self, loco: &LocomotiveInfo) -> f32 {
+        if !loco.powered {
+            return 0.0;
+        }
+        let level = f32::from(loco.zero_grade_t);
+        let two = f32::from(loco.two_grade_t);
+        let grade = self.grade.clamp(0.0, MAX_GRADE);
+        // With pull = effort / (resistance + grade), the two ratings give the
+        // rolling resistance in percent of grade and the tractive effort.
+        let pull = if two > 0.0 && level > two {
+            let resistance = 2.0 * two / (level - two);
+            let effort = level * resistance;
+            effort / (resistance + grade)
+        } else {
+            // Without two usable ratings, fall back to a straight line.
+            level + (two - level) * grade / 2.0
+        };
+        let mut adhesion = self.weather.adhesion(loco);
+        if self.sanding {
+            adhesion += (1.0 - adhesion).max(0.0) * SAND_RECOVERY;
+        }
+        (pull * adhesion).max(0.0)
+    }
+}
+
+/// Whether the consist can get over the grade in the chosen conditions.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Verdict {
+    NoPower,
+    /// The train is light enough, using the given share of what the power can pull.
+    Ok {
+        load: f32,
+    },
+    /// The train is heavier than the power can pull by the given tonnes.
+    TooHeavy {
+        excess: f32,
+    },
+}
+
+impl Verdict {
+    pub fn new(weight: f32, supported: f32) -> Self {
+        if supported <= 0.0 {
+            Self::NoPower
+        } else if weight <= supported {
+            Self::Ok {
+                load: weight / supported,
+            }
+        } else {
+            Self::TooHeavy {
+                excess: weight - supported,
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+use crate::conditions::Conditions;
+use crate::locomotive::{Locomotive, LocomotiveInfo, Placement};
+use crate::order::{Order, OrderStatus};
+use crate::speed::{SLOW_SPEED_KMH, SpeedLimit, SpeedLimiter};
+use crate::units::{Length, Mass, Units};
+
+/// The conditions locomotives have a tonnage rating for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize)]
+pub enum Rating {
+    ZeroGrade,
+    TwoGrade,
+    TwoGradeRain,
+}
+
+impl Display for Rating {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rating_str = match self {
+            Self::ZeroGrade => "0% grade",
+            Self::TwoGrade => "2% grade",
+            Self::TwoGradeRain => "2% grade in rain",
+        };
+        write!(f, "{rating_str}")
+    }
+}
+
+pub const RATINGS: [Rating; 3] = [Rating::ZeroGrade, Rating::TwoGrade, Rating::TwoGradeRain];
+
+/// The weight in tonnes the powered locomotives of a consist can pull in each
+/// rated condition.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Serialize)]
+pub struct SupportedWeights {
+    pub zero_grade: u16,
+    pub two_grade: u16,
+    pub rain: u16,
+}
+
+impl SupportedWeights {
+    pub fn get(&self, rating: Rating) -> u16 {
+        match rating {
+            Rating::ZeroGrade => self.zero_grade,
+            Rating::TwoGrade => self.two_grade,
+            Rating::TwoGradeRain => self.rain,
+        }
+    }
+}
+
+/// A locomotive or a cut of cars, as coupled in the train.
+#[derive(Clone, Copy)]
+pub enum TrainUnit<'a> {
+    Locomotive(&'a LocomotiveInfo),
+    Cut(&'a Order),
+}
+
+/// Something about a consist that will likely keep it from moving.
+#[derive(Clone, PartialEq, Debug, serde::Serialize)]
+pub enum ConsistWarning {
+    NoPoweredLocomotive,
+    Overweight {
+        rating: Rating,
+        weight: Mass,
+        supported: u16,
+    },
+    /// A locomotive that can only run slowly holds the whole train back.
+    SlowLocomotive {
+        loco: Locomotive,
+        reversed: bool,
+        kmh: u16,
+    },
+}
+
+impl ConsistWarning {
+    /// Describes the warning in English with weights in the given units, as
+    /// the command-line tool prints it. The app shows warnings in the chosen
+    /// language with [`I18n::warning`](crate::i18n::I18n::warning).
+    pub fn describe(&self, units: &Units) -> String {
+        match self {
+            Self::NoPoweredLocomotive => "The consist has no powered locomotive".to_owned(),
+            Self::Overweight {
+                rating,
+                weight,
+                supported,
+            } => format!(
+                "Total weight {} exceeds the {} supported on a {rating}",
+                units.mass(*weight),
+                units.mass(Mass::from_tonnes(f32::from(*supported)))
+            ),
+            Self::SlowLocomotive {
+                loco,
+                reversed,
+                kmh,
+            } => {
+                let running = if *reversed { " running in reverse" } else { "" };
+                format!("The {loco}{running} limits the train to {kmh} km/h")
+            }
+        }
+    }
+}
+
+impl Display for ConsistWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.describe(&Units::default()))
+    }
+}
+
+/// Weights, lengths and capacities of a consist, as computed by [`Consist::summary`].
+///
+/// Only orders that are on the train count toward the order and total figures.
+#[derive(Clone, Default, PartialEq, Debug, serde::Serialize)]
+pub struct ConsistSummary {
+    pub loco_weight: Mass,
+    pub order_weight: Mass,
+    /// The weight of the planned orders still waiting to be picked up.
+    pub planned_weight: Mass,
+    pub total_weight: Mass,
+    pub loco_length: Length,
+    pub order_length: Length,
+    pub total_length: Length,
+    pub supported_weights: SupportedWeights,
+    /// The number of places in the train with powered locomotives: 1 when all
+    /// the power is on the head end, more with distributed power.
+    pub power_positions: usize,
+    /// The fastest the train may run and what holds it to that.
+    pub max_speed: Option<SpeedLimit>,
+    pub warnings: Vec<ConsistWarning>,
+}
+
+/// A train: the locomotives pulling it and the orders it is carrying.
+#[derive(Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Consist {
+    pub locomotives: Vec<LocomotiveInfo>,
+    pub orders: Vec<Order>,
+    /// Jobs seen on a job board but not taken yet. They aren't part of the
+    /// train and don't count toward any of its figures.
+    pub jobs: Vec<Order>,
+}
+
+impl Consist {
+    /// The orders whose cars are currently on the train.
+    pub fn orders_on_train(&self) -> impl Iterator<Item = &Order> {
+        self.orders
+            .iter()
+            .filter(|o| o.status == OrderStatus::PickedUp)
+    }
+
+    /// Splits the locomotives into runs of identical, consecutive units, so
+    /// that e.g. three DE6s in a row can be shown and edited as one entry.
+    pub fn locomotive_groups(&self) -> Vec<Range<usize>> {
+        let mut groups: Vec<Range<usize>> = Vec::new();
+        for (ix, loco) in self.locomotives.iter().enumerate() {
+            match groups.last_mut() {
+                Some(group) if self.locomotives.get(group.start) == Some(loco) => {
+                    group.end = ix + 1;
+                }
+                _ => groups.push(ix..ix + 1),
+            }
+        }
+        groups
+    }
+
+    /// The group of identical locomotives the one at `index` belongs to.
+    pub fn locomotive_group(&self, index: usize) -> Option<Range<usize>> {
+        self.locomotive_groups()
+            .into_iter()
+            .find(|group| group.contains(&index))
+    }
+
+    /// How many cuts of cars a locomotive is coupled behind.
+    pub fn position(&self, loco: &LocomotiveInfo) -> usize {
+        let cuts = self.orders_on_train().count();
+        match loco.placement {
+            Placement::Head => 0,
+            Placement::AfterCut(n) => usize::from(n).min(cuts),
+            Placement::Rear => cuts,
+        }
+    }
+
+    /// The locomotives and cuts of cars in the order they are coupled, from
+    /// the head end to the rear.
+    pub fn units(&self) -> Vec<TrainUnit<'_>> {
+        let cuts: Vec<&Order> = self.orders_on_train().collect();
+        let mut units = Vec::new();
+        for position in 0..=cuts.len() {
+            // Locomotives placed after the last cut go ahead of those
+            // placed at the rear.
+            let mut locos: Vec<&LocomotiveInfo> = self
+                .locomotives
+                .iter()
+                .filter(|l| self.position(l) == position)
+                .collect();
+            locos.sort_by_key(|l| l.placement == Placement::Rear);
+            units.extend(locos.into_iter().map(TrainUnit::Locomotive));
+            if let Some(cut) = cuts.get(position) {
+                units.push(TrainUnit::Cut(cut));
+            }
+        }
+        units
+    }
+
+    /// The powered locomotives at each place in the train that has any.
+    fn power_blocks(&self) -> Vec<Vec<&LocomotiveInfo>> {
+        let cuts = self.orders_on_train().count();
+        (0..=cuts)
+            .map(|position| {
+                self.locomotives
+                    .iter()
+                    .filter(|l| l.powered && self.position(l) == position)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|block| !block.is_empty())
+            .collect()
+    }
+
+    /// Sums the tonnage ratings of every powered locomotive. With a coupler
+    /// limit, each place in the train with power can move at most the limit,
+    /// so power spread through the train can move more than the same power
+    /// all on the head end.
+    pub fn supported_weights(&self, coupler_limit: Option<Mass>) -> SupportedWeights {
+        let limit = coupler_limit.map_or(u16::MAX, |limit| {
+            limit.tonnes().round().clamp(0.0, f32::from(u16::MAX)) as u16
+        });
+        let block_rating = |block: &[&LocomotiveInfo], rating| {
+            block
+                .iter()
+                .fold(0u16, |a, l| a.saturating_add(l.rating(rating)))
+                .min(limit)
+        };
+        self.power_blocks()
+            .iter()
+            .fold(SupportedWeights::default(), |a, block| SupportedWeights {
+                zero_grade: a
+                    .zero_grade
+                    .saturating_add(block_rating(block, Rating::ZeroGrade)),
+                two_grade: a
+                    .two_grade
+                    .saturating_add(block_rating(block, Rating::TwoGrade)),
+                rain: a
+                    .rain
+                    .saturating_add(block_rating(block, Rating::TwoGradeRain)),
+            })
+    }
+
+    /// The weight the powered locomotives can pull in the given conditions,
+    /// limited like the rated weights by what each coupler can take.
+    pub fn supported_weight(&self, conditions: &Conditions) -> Mass {
+        let limit = conditions.coupler_limit.map_or(f32::INFINITY, Mass::tonnes);
+        let tonnes = self
+            .power_blocks()
+            .iter()
+            .map(|block| {
+                let pull: f32 = block.iter().map(|l| conditions.capacity(l)).sum();
+                pull.min(limit)
+            })
+            .sum();
+        Mass::from_tonnes(tonnes)
+    }
+
+    /// The lowest speed limit of the locomotives and the orders on the train,
+    /// or `None` for an empty consist. The first unit with that limit is
+    /// named as what sets it.
+    pub fn max_speed(&self) -> Option<SpeedLimit> {
+        let locomotives = self.locomotives.iter().map(|l| SpeedLimit {
+            kmh: l.max_speed_kmh(),
+            limiter: SpeedLimiter::Locomotive {
+                loco: l.loco,
+                reversed: l.reversed,
+            },
+        });
+        let orders = self.orders_on_train().filter_map(Order::speed_limit);
+        locomotives.chain(orders).min_by_key(|limit| limit.kmh)
+    }
+
+    /// Computes the weights, lengths and capacities of the consist, and what
+    /// might keep it from moving, with each coupler taking at most
+    /// `coupler_limit` if there is one.
+    pub fn summary(&self, coupler_limit: Option<Mass>) -> ConsistSummary {
+        let loco_weight: Mass = self.locomotives.iter().map(|l| l.weight).sum();
+        let order_weight: Mass = self.orders_on_train().map(|o| o.weight).sum();
+        let planned_weight = self
+            .orders
+            .iter()
+            .filter(|o| o.status == OrderStatus::Planned)
+            .map(|o| o.weight)
+            .sum();
+        let loco_length: Length = self.locomotives.iter().map(|l| l.length).sum();
+        let order_length: Length = self.orders_on_train().map(|o| o.length).sum();
+        let total_weight = loco_weight + order_weight;
+        let supported_weights = self.supported_weights(coupler_limit);
+        let max_speed = self.max_speed();
+
+        let mut warnings = Vec::new();
+        if self.locomotives.iter().any(|l| l.powered) {
+            for rating in RATINGS {
+                let supported = supported_weights.get(rating);
+                if total_weight.tonnes() > f32::from(supported) {
+                    warnings.push(ConsistWarning::Overweight {
+                        rating,
+                        weight: total_weight,
+                        supported,
+                    });
+                }
+            }
+        } else if !self.locomotives.is_empty() || self.orders_on_train().next().is_some() {
+            warnings.push(ConsistWarning::NoPoweredLocomotive);
+        }
+        if let Some(SpeedLimit {
+            kmh,
+            limiter: SpeedLimiter::Locomotive { loco, reversed },
+        }) = max_speed
+            && kmh < SLOW_SPEED_KMH
+        {
+            warnings.push(ConsistWarning::SlowLocomotive {
+                loco,
+                reversed,
+                kmh,
+            });
+        }
+
+        ConsistSummary {
+            loco_weight,
+            order_weight,
+            planned_weight,
+            total_weight,
+            loco_length,
+            order_length,
+            total_length: loco_length + order_length,
+            supported_weights,
+            power_positions: self.power_blocks().len(),
+            max_speed,
+            warnings,
+        }
+    }
+}
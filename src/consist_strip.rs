@@ -0,0 +1,169 @@
+//! A to-scale diagram of the train: every locomotive and every order's cut
+//! drawn as a block as long as it is, in the order they are coupled.
+
+use crate::consist::{Consist, TrainUnit};
+use crate::i18n::I18n;
+use crate::locomotive::LocomotiveInfo;
+use crate::order::Order;
+use crate::station::{STATIONS, Station};
+use crate::units::Units;
+
+const STRIP_HEIGHT: f32 = 36.0;
+const COUPLER_GAP: f32 = 2.0;
+const MIN_BLOCK_WIDTH: f32 = 3.0;
+
+/// A distinct color for each station, used for everything headed there.
+pub fn station_color(station: Station) -> egui::Color32 {
+    let ix = STATIONS.iter().position(|s| *s == station).unwrap_or(0);
+    // Step around the color wheel by the golden ratio so that neighbouring
+    // stations in the list don't end up with similar colors.
+    let hue = (ix as f32 * 0.618_034).fract();
+    egui::ecolor::Hsva::new(hue, 0.55, 0.85, 1.0).into()
+}
+
+struct Block {
+    label: String,
+    length: f32,
+    fill: egui::Color32,
+    text: egui::Color32,
+    tooltip: String,
+}
+
+fn loco_block(l: &LocomotiveInfo, i18n: &I18n, units: &Units) -> Block {
+    Block {
+        label: l.loco.to_string(),
+        length: l.length.meters(),
+        fill: egui::Color32::from_gray(80),
+        text: egui::Color32::WHITE,
+        tooltip: [
+            l.loco.to_string(),
+            i18n.tr_args("loco-details-weight", &[("weight", units.mass(l.weight))]),
+            i18n.tr_args("loco-details-length", &[("length", units.length(l.length))]),
+        ]
+        .join("\n"),
+    }
+}
+
+fn cut_block(o: &Order, i18n: &I18n, units: &Units) -> Block {
+    Block {
+        label: o.name.clone(),
+        length: o.length.meters(),
+        fill: station_color(o.dropoff_station),
+        text: egui::Color32::BLACK,
+        tooltip: [
+            o.name.clone(),
+            i18n.tr_args("loco-details-weight", &[("weight", units.mass(o.weight))]),
+            i18n.tr_args("loco-details-length", &[("length", units.length(o.length))]),
+            i18n.tr_args(
+                "strip-pickup",
+                &[
+                    ("station", o.pickup_station.to_abbrev()),
+                    ("track", o.pickup_track.clone()),
+                ],
+            ),
+            i18n.tr_args(
+                "strip-dropoff",
+                &[
+                    ("station", o.dropoff_station.to_abbrev()),
+                    ("track", o.dropoff_track.clone()),
+                ],
+            ),
+        ]
+        .join("\n"),
+    }
+}
+
+fn blocks(consist: &Consist, i18n: &I18n, units: &Units) -> Vec<Block> {
+    consist
+        .units()
+        .into_iter()
+        .map(|unit| match unit {
+            TrainUnit::Locomotive(l) => loco_block(l, i18n, units),
+            TrainUnit::Cut(o) => cut_block(o, i18n, units),
+        })
+        .collect()
+}
+
+fn strip_ui(ui: &mut egui::Ui, consist: &Consist, i18n: &I18n, units: &Units) -> egui::Response {
+    let blocks = blocks(consist, i18n, units);
+    let desired_size = egui::vec2(ui.available_width(), STRIP_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::hover());
+    let total_length: f32 = blocks.iter().map(|b| b.length).sum();
+    if blocks.is_empty() || total_length <= 0.0 || !ui.is_rect_visible(rect) {
+        return response;
+    }
+
+    let gaps = COUPLER_GAP * (blocks.len() - 1) as f32;
+    let scale = (rect.width() - gaps).max(0.0) / total_length;
+    let painter = ui.painter_at(rect);
+    let mut x = rect.left();
+    for (ix, block) in blocks.iter().enumerate() {
+        let width = (block.length * scale).max(MIN_BLOCK_WIDTH);
+        let block_rect =
+            egui::Rect::from_min_size(egui::pos2(x, rect.top()), egui::vec2(width, STRIP_HEIGHT));
+        painter.rect_filled(block_rect, 3.0, block.fill);
+
+        // Only label blocks that are wide enough to fit it.
+        let galley = painter.layout_no_wrap(
+            block.label.clone(),
+            egui::FontId::proportional(11.0),
+            block.text,
+        );
+        if galley.size().x + 4.0 <= width {
+            let pos = block_rect.center() - galley.size() / 2.0;
+            painter.galley(pos, galley, block.text);
+        }
+
+        ui.interact(block_rect, response.id.with(ix), egui::Sense::hover())
+            .on_hover_text(&block.tooltip);
+        x += width + COUPLER_GAP;
+    }
+    response
+}
+
+// One swatch for each dropoff station in the consist.
+fn legend_ui(ui: &mut egui::Ui, consist: &Consist, i18n: &I18n) {
+    let mut stations: Vec<Station> = Vec::new();
+    for order in consist.orders_on_train() {
+        if !stations.contains(&order.dropoff_station) {
+            stations.push(order.dropoff_station);
+        }
+    }
+    ui.horizontal_wrapped(|ui| {
+        for station in stations {
+            let size = egui::Vec2::splat(ui.text_style_height(&egui::TextStyle::Body));
+            let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+            ui.painter().rect_filled(rect, 2.0, station_color(station));
+            ui.label(station.to_abbrev())
+                .on_hover_text(i18n.station(station));
+            ui.add_space(6.0);
+        }
+    });
+}
+
+fn consist_strip_ui(
+    ui: &mut egui::Ui,
+    consist: &Consist,
+    i18n: &I18n,
+    units: &Units,
+) -> egui::Response {
+    ui.vertical(|ui| {
+        strip_ui(ui, consist, i18n, units);
+        legend_ui(ui, consist, i18n);
+    })
+    .response
+}
+
+/// A to-scale strip of the consist's locomotives and cuts.
+///
+/// ## Example:
+/// ``` ignore
+/// ui.add(consist_strip(&consist, &i18n, &units));
+/// ```
+pub fn consist_strip<'a>(
+    consist: &'a Consist,
+    i18n: &'a I18n,
+    units: &'a Units,
+) -> impl egui::Widget + 'a {
+    move |ui: &mut egui::Ui| consist_strip_ui(ui, consist, i18n, units)
+}
@@ -0,0 +1,167 @@
+//! The line between stations and how steep it is.
+//!
+//! The map is a graph of segments, each joining two neighbouring stations.
+//! A segment's ruling grade is the steepest climb a train faces anywhere on
+//! it, which depends on the direction: the way down still has the odd short
+//! rise, but nothing like the climb the other way.
+//!
+//! The data is provisional: the segments, their grades and the station
+//! heights they have to agree with are estimates, not figures taken from the
+//! game's track data. The app says so next to anything worked out from them,
+//! and they should be replaced with measured grades when those are available.
+
+use crate::station::Station;
+
+/// Track joining two neighbouring stations.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Segment {
+    pub a: Station,
+    pub b: Station,
+    /// The ruling grade going from `a` to `b`, in percent.
+    pub grade_ab: f32,
+    /// The ruling grade going from `b` to `a`, in percent.
+    pub grade_ba: f32,
+}
+
+impl Segment {
+    const fn new(a: Station, b: Station, grade_ab: f32, grade_ba: f32) -> Self {
+        Self {
+            a,
+            b,
+            grade_ab,
+            grade_ba,
+        }
+    }
+
+    /// The ruling grade going from `from` to the other end, if the segment
+    /// starts or ends there.
+    pub fn ruling_grade_from(&self, from: Station) -> Option<f32> {
+        if from == self.a {
+            Some(self.grade_ab)
+        } else if from == self.b {
+            Some(self.grade_ba)
+        } else {
+            None
+        }
+    }
+
+    fn other_end(&self, station: Station) -> Option<Station> {
+        if station == self.a {
+            Some(self.b)
+        } else if station == self.b {
+            Some(self.a)
+        } else {
+            None
+        }
+    }
+}
+
+pub const SEGMENTS: [Segment; 29] = [
+    Segment::new(Station::Harbor, Station::OilRefinery, 0.7, 0.3),
+    Segment::new(Station::Harbor, Station::CityWest, 1.8, 0.6),
+    Segment::new(Station::OilRefinery, Station::FoodFactory, 1.6, 0.5),
+    Segment::new(Station::FoodFactory, Station::CityWest, 1.3, 0.5),
+    Segment::new(Station::CityWest, Station::CitySouth, 0.4, 0.9),
+    Segment::new(Station::CitySouth, Station::CoalMineSouth, 1.5, 0.5),
+    Segment::new(Station::CoalMineSouth, Station::Farm, 0.9, 0.4),
+    Segment::new(Station::Farm, Station::GoodsFactory, 0.9, 0.4),
+    Segment::new(Station::GoodsFactory, Station::CitySouth, 0.6, 2.0),
+    Segment::new(Station::CityWest, Station::ForestSouth, 3.0, 0.9),
+    Segment::new(Station::ForestSouth, Station::FoodFactory, 0.9, 3.0),
+    Segment::new(Station::ForestSouth, Station::ForestCentral, 2.3, 0.7),
+    Segment::new(Station::ForestCentral, Station::GoodsFactory, 0.8, 2.5),
+    Segment::new(Station::ForestCentral, Station::OilWellCentral, 0.4, 1.1),
+    Segment::new(Station::OilWellCentral, Station::MachineFactory, 0.5, 1.5),
+    Segment::new(Station::MachineFactory, Station::FoodFactory, 0.7, 2.5),
+    Segment::new(Station::MachineFactory, Station::MilitaryBase, 2.6, 0.8),
+    Segment::new(Station::MilitaryBase, Station::IronMineWest, 0.8, 0.4),
+    Segment::new(Station::IronMineWest, Station::OilWellCentral, 0.8, 2.6),
+    Segment::new(Station::IronMineWest, Station::OilWellNorth, 0.6, 0.3),
+    Segment::new(Station::OilWellNorth, Station::SteelMill, 0.4, 1.1),
+    Segment::new(Station::SteelMill, Station::Sawmill, 0.5, 1.6),
+    Segment::new(Station::Sawmill, Station::ForestCentral, 0.5, 1.3),
+    Segment::new(Station::Sawmill, Station::CoalMineEast, 0.4, 0.8),
+    Segment::new(Station::CoalMineEast, Station::IronMineEast, 2.4, 0.7),
+    Segment::new(Station::IronMineEast, Station::SteelMill, 0.5, 1.5),
+    Segment::new(Station::CoalMineEast, Station::CoalPowerPlant, 0.6, 2.0),
+    Segment::new(Station::CoalPowerPlant, Station::Farm, 0.5, 1.4),
+    Segment::new(Station::CoalPowerPlant, Station::GoodsFactory, 0.5, 1.2),
+];
+
+fn distance(a: Station, b: Station) -> f32 {
+    let (ax, ay) = a.map_position();
+    let (bx, by) = b.map_position();
+    (ax - bx).hypot(ay - by)
+}
+
+struct Visit {
+    station: Station,
+    distance: f32,
+    previous: Option<Station>,
+    done: bool,
+}
+
+/// The stations passed through on the shortest way from one station to
+/// another, including both ends. `None` if there is no way between them.
+pub fn path(from: Station, to: Station) -> Option<Vec<Station>> {
+    // Dijkstra's algorithm; the graph is small enough to scan every station
+    // for the next closest one.
+    let mut visits = vec![Visit {
+        station: from,
+        distance: 0.0,
+        previous: None,
+        done: false,
+    }];
+    loop {
+        let visit = visits
+            .iter_mut()
+            .filter(|v| !v.done)
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))?;
+        visit.done = true;
+        let (station, so_far) = (visit.station, visit.distance);
+        if station == to {
+            break;
+        }
+        for next in SEGMENTS.iter().filter_map(|s| s.other_end(station)) {
+            let distance = so_far + self::distance(station, next);
+            match visits.iter_mut().find(|v| v.station == next) {
+                Some(visit) if !visit.done && distance < visit.distance => {
+                    visit.distance = distance;
+                    visit.previous = Some(station);
+                }
+                Some(_) => {}
+                None => visits.push(Visit {
+                    station: next,
+                    distance,
+                    previous: Some(station),
+                    done: false,
+                }),
+            }
+        }
+    }
+    let mut path = vec![to];
+    let mut current = to;
+    while let Some(previous) = visits
+        .iter()
+        .find(|v| v.station == current)
+        .and_then(|v| v.previous)
+    {
+        path.push(previous);
+        current = previous;
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// The steepest climb on the way from one station to another, in percent.
+pub fn ruling_grade(from: Station, to: Station) -> Option<f32> {
+    let path = path(from, to)?;
+    let grades = path.windows(2).filter_map(|pair| match pair {
+        [a, b] => SEGMENTS
+            .iter()
+            .find(|s| s.other_end(*a) == Some(*b))
+            .and_then(|s| s.ruling_grade_from(*a)),
+        _ => None,
+    });
+    Some(grades.fold(0.0, f32::max))
+}
@@ -0,0 +1,293 @@
+//! Hazardous cargo classes and where cuts carrying them may go in the train.
+//!
+//! A hazmat cut must not be coupled next to a locomotive or the caboose, and
+//! cuts of different classes need a buffer of ordinary cars between them.
+//! When the train breaks these rules, [`safe_order`] looks for an order of
+//! the cuts that doesn't, leaving the locomotives where they are.
+
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+use crate::consist::Consist;
+use crate::order::{Order, OrderStatus};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Deserialize, serde::Serialize)]
+pub enum HazmatClass {
+    Flammable,
+    Explosive,
+    Gas,
+}
+
+impl Display for HazmatClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let class_str = match self {
+            Self::Flammable => "Flammable",
+            Self::Explosive => "Explosive",
+            Self::Gas => "Gas",
+        };
+        write!(f, "{class_str}")
+    }
+}
+
+pub const HAZMAT_CLASSES: [HazmatClass; 3] = [
+    HazmatClass::Flammable,
+    HazmatClass::Explosive,
+    HazmatClass::Gas,
+];
+
+/// Words in the names of hazardous cargo, as the game and its job overview
+/// spell them, and the class of each.
+const HAZMAT_CARGO: [(&str, HazmatClass); 12] = [
+    ("explosive", HazmatClass::Explosive),
+    ("ammonia", HazmatClass::Gas),
+    ("chlorine", HazmatClass::Gas),
+    ("argon", HazmatClass::Gas),
+    ("nitrogen", HazmatClass::Gas),
+    ("oxygen", HazmatClass::Gas),
+    ("hydrogen", HazmatClass::Gas),
+    ("methane", HazmatClass::Gas),
+    ("alcohol", HazmatClass::Flammable),
+    ("gasoline", HazmatClass::Flammable),
+    ("diesel", HazmatClass::Flammable),
+    ("crudeoil", HazmatClass::Flammable),
+];
+
+impl HazmatClass {
+    /// The class of an order's cargo, e.g. `SodiumHydroxide, Methane` or
+    /// `Crude oil`, if any of it is hazardous. Explosives count before gas,
+    /// and gas before flammable liquids.
+    pub fn from_cargo(cargo: &str) -> Option<Self> {
+        let cargo: String = cargo
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        [Self::Explosive, Self::Gas, Self::Flammable]
+            .into_iter()
+            .find(|class| {
+                HAZMAT_CARGO
+                    .iter()
+                    .any(|(word, c)| c == class && cargo.contains(word))
+            })
+    }
+
+    /// Whether cuts of the two classes may be coupled together. Only cuts of
+    /// the same class may.
+    pub fn compatible_with(self, other: Self) -> bool {
+        self == other
+    }
+}
+
+/// Whether a cut of cars with the given class may be coupled right behind
+/// one with the other, with no locomotive between them.
+fn can_follow(first: Option<HazmatClass>, second: Option<HazmatClass>) -> bool {
+    match (first, second) {
+        (Some(a), Some(b)) => a.compatible_with(b),
+        _ => true,
+    }
+}
+
+/// The orders on the train, in the order they are coupled, and which gaps
+/// between them have locomotives in them.
+struct Layout {
+    cuts: Vec<usize>,
+    /// Gap `n` is right behind the `n`th cut, so gap 0 is the head end and
+    /// the last gap the rear.
+    locomotive_gaps: Vec<bool>,
+}
+
+impl Layout {
+    fn new(consist: &Consist) -> Self {
+        let cuts: Vec<usize> = consist
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.status == OrderStatus::PickedUp)
+            .map(|(ix, _)| ix)
+            .collect();
+        let mut locomotive_gaps = vec![false; cuts.len() + 1];
+        for loco in &consist.locomotives {
+            if let Some(gap) = locomotive_gaps.get_mut(consist.position(loco)) {
+                *gap = true;
+            }
+        }
+        Self {
+            cuts,
+            locomotive_gaps,
+        }
+    }
+
+    fn gap(&self, position: usize) -> bool {
+        self.locomotive_gaps.get(position).copied().unwrap_or(false)
+    }
+}
+
+fn class(consist: &Consist, ix: usize) -> Option<HazmatClass> {
+    consist.orders.get(ix).and_then(Order::hazmat_class)
+}
+
+/// The hazmat orders on the train coupled right next to a locomotive or the
+/// caboose, as indices into the consist's `orders`.
+pub fn next_to_locomotive(consist: &Consist) -> Vec<usize> {
+    let layout = Layout::new(consist);
+    layout
+        .cuts
+        .iter()
+        .enumerate()
+        .filter(|(position, ix)| {
+            class(consist, **ix).is_some() && (layout.gap(*position) || layout.gap(position + 1))
+        })
+        .map(|(_, ix)| *ix)
+        .collect()
+}
+
+/// Neighbouring hazmat orders on the train of classes that need a buffer
+/// between them, as pairs of indices into the consist's `orders`.
+pub fn unseparated(consist: &Consist) -> Vec<(usize, usize)> {
+    let layout = Layout::new(consist);
+    layout
+        .cuts
+        .windows(2)
+        .enumerate()
+        .filter_map(|(position, pair)| match *pair {
+            [first, second]
+                if !layout.gap(position + 1)
+                    && !can_follow(class(consist, first), class(consist, second)) =>
+            {
+                Some((first, second))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// A safe order for the cuts on the train, if there is one.
+///
+/// Safe means hazmat is kept away from the locomotives and the caboose, and
+/// incompatible classes are separated. The result is the new order of every
+/// order of the consist, as indices into its `orders`. Only the orders on
+/// the train move, and each class keeps its cuts in the order they were in.
+pub fn safe_order(consist: &Consist) -> Option<Vec<usize>> {
+    let layout = Layout::new(consist);
+    let on_train = &layout.cuts;
+
+    // The cuts of each kind, from last to first so they can be popped off
+    // in their original order.
+    let kinds: Vec<Option<HazmatClass>> = std::iter::once(None)
+        .chain(HAZMAT_CLASSES.map(Some))
+        .collect();
+    let mut queues: Vec<Vec<usize>> = kinds
+        .iter()
+        .map(|kind| {
+            on_train
+                .iter()
+                .rev()
+                .copied()
+                .filter(|ix| class(consist, *ix) == *kind)
+                .collect()
+        })
+        .collect();
+    let preferred: Vec<Option<HazmatClass>> =
+        on_train.iter().map(|ix| class(consist, *ix)).collect();
+
+    let search = Search {
+        kinds: &kinds,
+        layout: &layout,
+        preferred: &preferred,
+    };
+    let mut counts: Vec<usize> = queues.iter().map(Vec::len).collect();
+    let mut sequence = Vec::with_capacity(on_train.len());
+    let mut dead_ends = HashSet::new();
+    if !search.place(&mut counts, &mut sequence, &mut dead_ends) {
+        return None;
+    }
+
+    let mut arranged = sequence
+        .into_iter()
+        .filter_map(|kind| queues.get_mut(kind).and_then(Vec::pop));
+    Some(
+        (0..consist.orders.len())
+            .map(|ix| {
+                if on_train.contains(&ix) {
+                    arranged.next().unwrap_or(ix)
+                } else {
+                    ix
+                }
+            })
+            .collect(),
+    )
+}
+
+/// A depth-first search for a safe sequence of kinds of cut, where a kind
+/// is either no hazmat or one of the classes.
+struct Search<'a> {
+    kinds: &'a [Option<HazmatClass>],
+    layout: &'a Layout,
+    /// The kind of cut at each place now, tried first to move as little as
+    /// possible.
+    preferred: &'a [Option<HazmatClass>],
+}
+
+impl Search<'_> {
+    // Extends `sequence` with the kinds of the remaining cuts, as counted
+    // in `counts`. States already known not to work are kept in
+    // `dead_ends`, so each is only explored once.
+    fn place(
+        &self,
+        counts: &mut [usize],
+        sequence: &mut Vec<usize>,
+        dead_ends: &mut HashSet<(Vec<usize>, Option<usize>)>,
+    ) -> bool {
+        let position = sequence.len();
+        let previous = sequence.last().copied();
+        if counts.iter().all(|c| *c == 0) {
+            // The last cut can't be hazmat if locomotives follow it.
+            let rear_ok =
+                !self.layout.gap(position) || previous.is_none_or(|kind| self.kind(kind).is_none());
+            return rear_ok;
+        }
+        let state = (counts.to_vec(), previous);
+        if dead_ends.contains(&state) {
+            return false;
+        }
+        let preferred = self
+            .preferred
+            .get(position)
+            .and_then(|p| self.kinds.iter().position(|k| k == p));
+        let candidates = preferred
+            .into_iter()
+            .chain((0..self.kinds.len()).filter(|k| Some(*k) != preferred));
+        for kind in candidates {
+            if counts.get(kind).is_none_or(|c| *c == 0) {
+                continue;
+            }
+            let class = self.kind(kind);
+            let fits = if self.layout.gap(position) {
+                // Locomotives right ahead: no hazmat on either side of them.
+                class.is_none() && previous.is_none_or(|p| self.kind(p).is_none())
+            } else {
+                can_follow(previous.and_then(|p| self.kind(p)), class)
+            };
+            if !fits {
+                continue;
+            }
+            if let Some(count) = counts.get_mut(kind) {
+                *count -= 1;
+            }
+            sequence.push(kind);
+            if self.place(counts, sequence, dead_ends) {
+                return true;
+            }
+            sequence.pop();
+            if let Some(count) = counts.get_mut(kind) {
+                *count += 1;
+            }
+        }
+        dead_ends.insert(state);
+        false
+    }
+
+    fn kind(&self, kind: usize) -> Option<HazmatClass> {
+        self.kinds.get(kind).copied().flatten()
+    }
+}
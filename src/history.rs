@@ -0,0 +1,279 @@
+use std::collections::VecDeque;
+
+use crate::consist::Consist;
+use crate::locomotive::{LocomotiveInfo, Placement};
+use crate::order::{Order, OrderStatus};
+
+/// The maximum number of commands kept in the undo history.
+pub const MAX_HISTORY: usize = 100;
+
+/// A single reversible edit to the consist.
+#[derive(Clone)]
+pub enum Command {
+    AddLocomotive {
+        index: usize,
+        loco: LocomotiveInfo,
+    },
+    DeleteLocomotive {
+        index: usize,
+        loco: LocomotiveInfo,
+    },
+    TogglePower {
+        index: usize,
+    },
+    ToggleReversed {
+        index: usize,
+    },
+    MoveLocomotive {
+        from: usize,
+        to: usize,
+    },
+    SetPlacement {
+        index: usize,
+        old: Placement,
+        new: Placement,
+    },
+    AddOrder {
+        index: usize,
+        order: Order,
+    },
+    EditOrder {
+        index: usize,
+        old: Order,
+        new: Order,
+    },
+    DeleteOrder {
+        index: usize,
+        order: Order,
+    },
+    MoveOrder {
+        from: usize,
+        to: usize,
+    },
+    SetOrderStatus {
+        index: usize,
+        old: OrderStatus,
+        new: OrderStatus,
+    },
+    AddJob {
+        index: usize,
+        job: Order,
+    },
+    DeleteJob {
+        index: usize,
+        job: Order,
+    },
+    /// Moves a job from the job board into the orders.
+    TakeJob {
+        job: usize,
+        order: usize,
+    },
+    /// Moves an order back onto the job board.
+    ReturnJob {
+        order: usize,
+        job: usize,
+    },
+    /// Several commands that are undone and redone as one.
+    Batch(Vec<Command>),
+}
+
+impl Command {
+    /// Applies the command. Returns false if its indices no longer fit the
+    /// consist, in which case nothing changed.
+    fn apply(&self, consist: &mut Consist) -> bool {
+        match self {
+            Self::AddLocomotive { index, loco } => {
+                insert(&mut consist.locomotives, *index, loco.clone())
+            }
+            Self::DeleteLocomotive { index, .. } => {
+                remove(&mut consist.locomotives, *index).is_some()
+            }
+            Self::TogglePower { index } => consist
+                .locomotives
+                .get_mut(*index)
+                .map(|loco| loco.powered = !loco.powered)
+                .is_some(),
+            Self::ToggleReversed { index } => consist
+                .locomotives
+                .get_mut(*index)
+                .map(|loco| loco.reversed = !loco.reversed)
+                .is_some(),
+            Self::MoveLocomotive { from, to } => relocate(&mut consist.locomotives, *from, *to),
+            Self::SetPlacement { index, new, .. } => consist
+                .locomotives
+                .get_mut(*index)
+                .map(|loco| loco.placement = *new)
+                .is_some(),
+            Self::AddOrder { index, order } => insert(&mut consist.orders, *index, order.clone()),
+            Self::EditOrder { index, new, .. } => consist
+                .orders
+                .get_mut(*index)
+                .map(|order| *order = new.clone())
+                .is_some(),
+            Self::DeleteOrder { index, .. } => remove(&mut consist.orders, *index).is_some(),
+            Self::MoveOrder { from, to } => relocate(&mut consist.orders, *from, *to),
+            Self::SetOrderStatus { index, new, .. } => consist
+                .orders
+                .get_mut(*index)
+                .map(|order| order.status = *new)
+                .is_some(),
+            Self::AddJob { index, job } => insert(&mut consist.jobs, *index, job.clone()),
+            Self::DeleteJob { index, .. } => remove(&mut consist.jobs, *index).is_some(),
+            Self::TakeJob { job, order } => {
+                transfer(&mut consist.jobs, *job, &mut consist.orders, *order)
+            }
+            Self::ReturnJob { order, job } => {
+                transfer(&mut consist.orders, *order, &mut consist.jobs, *job)
+            }
+            Self::Batch(commands) => commands
+                .iter()
+                .fold(false, |applied, command| command.apply(consist) || applied),
+        }
+    }
+
+    /// Applies the command and returns what of it applied: the command
+    /// itself, or for a batch only the commands that did. Returns `None`
+    /// if nothing applied.
+    fn apply_recorded(self, consist: &mut Consist) -> Option<Self> {
+        match self {
+            Self::Batch(commands) => {
+                let applied: Vec<_> = commands
+                    .into_iter()
+                    .filter_map(|command| command.apply_recorded(consist))
+                    .collect();
+                (!applied.is_empty()).then_some(Self::Batch(applied))
+            }
+            command => command.apply(consist).then_some(command),
+        }
+    }
+
+    /// Undoes the command. Returns false if its indices no longer fit.
+    fn revert(&self, consist: &mut Consist) -> bool {
+        match self {
+            Self::AddLocomotive { index, .. } => remove(&mut consist.locomotives, *index).is_some(),
+            Self::DeleteLocomotive { index, loco } => {
+                insert(&mut consist.locomotives, *index, loco.clone())
+            }
+            Self::TogglePower { .. } | Self::ToggleReversed { .. } => self.apply(consist),
+            Self::MoveLocomotive { from, to } => relocate(&mut consist.locomotives, *to, *from),
+            Self::SetPlacement { index, old, .. } => consist
+                .locomotives
+                .get_mut(*index)
+                .map(|loco| loco.placement = *old)
+                .is_some(),
+            Self::AddOrder { index, .. } => remove(&mut consist.orders, *index).is_some(),
+            Self::EditOrder { index, old, .. } => consist
+                .orders
+                .get_mut(*index)
+                .map(|order| *order = old.clone())
+                .is_some(),
+            Self::DeleteOrder { index, order } => {
+                insert(&mut consist.orders, *index, order.clone())
+            }
+            Self::MoveOrder { from, to } => relocate(&mut consist.orders, *to, *from),
+            Self::SetOrderStatus { index, old, .. } => consist
+                .orders
+                .get_mut(*index)
+                .map(|order| order.status = *old)
+                .is_some(),
+            Self::AddJob { index, .. } => remove(&mut consist.jobs, *index).is_some(),
+            Self::DeleteJob { index, job } => insert(&mut consist.jobs, *index, job.clone()),
+            Self::TakeJob { job, order } => {
+                transfer(&mut consist.orders, *order, &mut consist.jobs, *job)
+            }
+            Self::ReturnJob { order, job } => {
+                transfer(&mut consist.jobs, *job, &mut consist.orders, *order)
+            }
+            Self::Batch(commands) => commands.iter().rev().fold(false, |reverted, command| {
+                command.revert(consist) || reverted
+            }),
+        }
+    }
+}
+
+// Commands can be built from a consist that has changed since, e.g. by a
+// window showing an older frame, so stored indices are checked rather than
+// trusted. A command whose indices no longer fit does nothing and reports
+// that it didn't apply.
+fn insert<T>(list: &mut Vec<T>, index: usize, item: T) -> bool {
+    let fits = index <= list.len();
+    if fits {
+        list.insert(index, item);
+    }
+    fits
+}
+
+fn remove<T>(list: &mut Vec<T>, index: usize) -> Option<T> {
+    (index < list.len()).then(|| list.remove(index))
+}
+
+fn relocate<T>(list: &mut Vec<T>, from: usize, to: usize) -> bool {
+    let fits = from < list.len() && to < list.len();
+    if fits {
+        let item = list.remove(from);
+        list.insert(to, item);
+    }
+    fits
+}
+
+// Moves an item between two lists, if it is there and has somewhere to go.
+fn transfer<T>(from: &mut Vec<T>, from_index: usize, to: &mut Vec<T>, to_index: usize) -> bool {
+    let fits = from_index < from.len() && to_index <= to.len();
+    if fits {
+        let item = from.remove(from_index);
+        to.insert(to_index, item);
+    }
+    fits
+}
+
+/// A bounded undo/redo stack of commands applied to the consist.
+#[derive(Default)]
+pub struct History {
+    undo_stack: VecDeque<Command>,
+    redo_stack: Vec<Command>,
+}
+
+impl History {
+    /// Applies the command and records it so it can be undone later. A
+    /// command whose indices no longer fit, e.g. because it was built for a
+    /// consist that has changed since, isn't recorded; of a batch only the
+    /// commands that applied are.
+    pub fn execute(&mut self, command: Command, consist: &mut Consist) {
+        let Some(command) = command.apply_recorded(consist) else {
+            return;
+        };
+        self.redo_stack.clear();
+        if self.undo_stack.len() == MAX_HISTORY {
+            self.undo_stack.pop_front();
+        }
+        self.undo_stack.push_back(command);
+    }
+
+    /// Reverts the most recent command. Returns false if there was nothing to undo.
+    pub fn undo(&mut self, consist: &mut Consist) -> bool {
+        let Some(command) = self.undo_stack.pop_back() else {
+            return false;
+        };
+        command.revert(consist);
+        self.redo_stack.push(command);
+        true
+    }
+
+    /// Re-applies the most recently undone command. Returns false if there was nothing to redo.
+    pub fn redo(&mut self, consist: &mut Consist) -> bool {
+        let Some(command) = self.redo_stack.pop() else {
+            return false;
+        };
+        command.apply(consist);
+        self.undo_stack.push_back(command);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
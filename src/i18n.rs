@@ -0,0 +1,305 @@
+//! Translations of the UI text, loaded from the Fluent catalogs in `assets/i18n`.
+//!
+//! English is the fallback: a message missing from the selected language's
+//! catalog is shown in English, and a message missing from both is shown as
+//! its key so the gap is easy to spot.
+
+use std::fmt::{self, Display, Formatter};
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::consist::{ConsistWarning, Rating};
+use crate::hazmat::HazmatClass;
+use crate::keymap::Action;
+use crate::order::Order;
+use crate::rules::{Rule, Severity, Violation};
+use crate::speed::SpeedLimiter;
+use crate::station::Station;
+use crate::units::{LengthUnit, Mass, MassUnit, Units};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    Polish,
+}
+
+impl Display for Language {
+    // Each language is listed under its own name, so it can be found by
+    // someone who can't read the current one.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let language_str = match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+            Self::Polish => "Polski",
+        };
+        write!(f, "{language_str}")
+    }
+}
+
+impl Language {
+    /// The language's BCP 47 code, e.g. `de`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::Polish => "pl",
+        }
+    }
+
+    /// The source of the language's Fluent catalog.
+    pub fn catalog(self) -> &'static str {
+        match self {
+            Self::English => include_str!("../assets/i18n/en.ftl"),
+            Self::German => include_str!("../assets/i18n/de.ftl"),
+            Self::Polish => include_str!("../assets/i18n/pl.ftl"),
+        }
+    }
+}
+
+pub const LANGUAGES: [Language; 3] = [Language::English, Language::German, Language::Polish];
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = language
+        .code()
+        .parse()
+        .expect("Language codes are valid identifiers");
+    let resource = FluentResource::try_new(language.catalog().to_owned())
+        .expect("Translation catalogs are valid Fluent");
+    let mut bundle = FluentBundle::new(vec![id]);
+    // Unicode isolation marks show up as boxes in egui's fonts.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("Translation catalogs have no duplicate messages");
+    bundle
+}
+
+fn format(
+    bundle: &FluentBundle<FluentResource>,
+    key: &str,
+    args: Option<&FluentArgs<'_>>,
+) -> Option<String> {
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    errors.is_empty().then(|| text.into_owned())
+}
+
+/// Looks up UI text in the selected language.
+pub struct I18n {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Default for I18n {
+    fn default() -> Self {
+        Self::new(Language::default())
+    }
+}
+
+impl I18n {
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            bundle: bundle(language),
+            fallback: bundle(Language::English),
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The text of a message.
+    pub fn tr(&self, key: &str) -> String {
+        self.tr_args(key, &[])
+    }
+
+    /// The text of a message with its `{ $name }` placeables filled in.
+    pub fn tr_args(&self, key: &str, args: &[(&str, String)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        let args = (!args.is_empty()).then_some(&fluent_args);
+        format(&self.bundle, key, args)
+            .or_else(|| format(&self.fallback, key, args))
+            .unwrap_or_else(|| key.to_owned())
+    }
+
+    /// The translated name of a station.
+    pub fn station(&self, station: Station) -> String {
+        self.tr(&station_key(station))
+    }
+
+    pub fn rating(&self, rating: Rating) -> String {
+        self.tr(match rating {
+            Rating::ZeroGrade => "rating-zero-grade",
+            Rating::TwoGrade => "rating-two-grade",
+            Rating::TwoGradeRain => "rating-two-grade-rain",
+        })
+    }
+
+    /// Describes a consist warning with weights in the given units.
+    pub fn warning(&self, warning: &ConsistWarning, units: &Units) -> String {
+        match warning {
+            ConsistWarning::NoPoweredLocomotive => self.tr("warning-no-power"),
+            ConsistWarning::Overweight {
+                rating,
+                weight,
+                supported,
+            } => self.tr_args(
+                "warning-overweight",
+                &[
+                    ("weight", units.mass(*weight)),
+                    (
+                        "supported",
+                        units.mass(Mass::from_tonnes(f32::from(*supported))),
+                    ),
+                    ("rating", self.rating(*rating)),
+                ],
+            ),
+            ConsistWarning::SlowLocomotive {
+                loco,
+                reversed,
+                kmh,
+            } => self.tr_args(
+                if *reversed {
+                    "warning-slow-locomotive-reversed"
+                } else {
+                    "warning-slow-locomotive"
+                },
+                &[("loco", loco.to_string()), ("speed", kmh.to_string())],
+            ),
+        }
+    }
+
+    pub fn rule(&self, rule: Rule) -> String {
+        self.tr(rule_key(rule))
+    }
+
+    pub fn severity(&self, severity: Severity) -> String {
+        self.tr(match severity {
+            Severity::Info => "severity-info",
+            Severity::Warning => "severity-warning",
+            Severity::Error => "severity-error",
+        })
+    }
+
+    /// Describes a broken rule, naming the orders that break it if any.
+    pub fn violation(&self, violation: &Violation, orders: &[Order]) -> String {
+        let key = format!(
+            "violation-{}",
+            rule_key(violation.rule).trim_start_matches("rule-")
+        );
+        let names: Vec<(&str, String)> = ["order", "other"]
+            .into_iter()
+            .zip(&violation.orders)
+            .filter_map(|(arg, ix)| Some((arg, orders.get(*ix)?.name.clone())))
+            .collect();
+        self.tr_args(&key, &names)
+    }
+
+    pub fn hazmat_class(&self, class: HazmatClass) -> String {
+        self.tr(match class {
+            HazmatClass::Flammable => "hazmat-flammable",
+            HazmatClass::Explosive => "hazmat-explosive",
+            HazmatClass::Gas => "hazmat-gas",
+        })
+    }
+
+    pub fn action(&self, action: Action) -> String {
+        self.tr(match action {
+            Action::AddOrder => "action-add-order",
+            Action::AddLocomotive => "action-add-locomotive",
+            Action::PasteJobs => "action-paste-jobs",
+            Action::EditSelected => "action-edit-selected",
+            Action::DeleteSelected => "action-delete-selected",
+            Action::MoveUp => "action-move-up",
+            Action::MoveDown => "action-move-down",
+            Action::SelectPrevious => "action-select-previous",
+            Action::SelectNext => "action-select-next",
+            Action::TogglePower => "action-toggle-power",
+            Action::Undo => "action-undo",
+            Action::Redo => "action-redo",
+            Action::StationMap => "action-station-map",
+            Action::Trip => "action-trip",
+            Action::Checks => "action-checks",
+            Action::SuggestPower => "action-suggest-power",
+            Action::JobBoard => "action-job-board",
+            Action::Shortcuts => "action-shortcuts",
+            Action::CommandPalette => "action-command-palette",
+        })
+    }
+
+    pub fn mass_unit(&self, unit: MassUnit) -> String {
+        self.tr(match unit {
+            MassUnit::Tonnes => "unit-tonnes",
+            MassUnit::ShortTons => "unit-short-tons",
+        })
+    }
+
+    pub fn length_unit(&self, unit: LengthUnit) -> String {
+        self.tr(match unit {
+            LengthUnit::Meters => "unit-meters",
+            LengthUnit::Feet => "unit-feet",
+        })
+    }
+
+    /// Names what limits the train's speed.
+    pub fn speed_limiter(&self, limiter: &SpeedLimiter) -> String {
+        match limiter {
+            SpeedLimiter::Locomotive {
+                loco,
+                reversed: false,
+            } => loco.to_string(),
+            SpeedLimiter::Locomotive {
+                loco,
+                reversed: true,
+            } => self.tr_args("limiter-reversed", &[("loco", loco.to_string())]),
+            SpeedLimiter::CarType(car_type) => {
+                self.tr_args("limiter-car-type", &[("car", car_type.to_string())])
+            }
+            SpeedLimiter::Hazmat(order) => {
+                self.tr_args("limiter-hazmat", &[("order", order.clone())])
+            }
+            SpeedLimiter::Order(order) => {
+                self.tr_args("limiter-order", &[("order", order.clone())])
+            }
+        }
+    }
+}
+
+/// The catalog key for a station's name, e.g. `station-sm`.
+pub fn station_key(station: Station) -> String {
+    format!("station-{}", station.to_abbrev().to_lowercase())
+}
+
+fn rule_key(rule: Rule) -> &'static str {
+    match rule {
+        Rule::CabooseLast => "rule-caboose-last",
+        Rule::HazmatNextToLocomotive => "rule-hazmat-next-to-locomotive",
+        Rule::HazmatSeparation => "rule-hazmat-separation",
+        Rule::SlugNextToDe6 => "rule-slug-next-to-de6",
+        Rule::ZeroWeightOrder => "rule-zero-weight-order",
+    }
+}
+
+/// The ids of the messages defined in a Fluent catalog, in the order they appear.
+pub fn message_ids(source: &str) -> Vec<String> {
+    let resource = match fluent_syntax::parser::parse(source) {
+        Ok(resource) | Err((resource, _)) => resource,
+    };
+    resource
+        .body
+        .into_iter()
+        .filter_map(|entry| match entry {
+            fluent_syntax::ast::Entry::Message(message) => Some(message.id.name.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,347 @@
+//! A window listing the jobs seen at each station but not taken yet, where
+//! the player picks which to take within what the train can still carry.
+//!
+//! A station's pool is the jobs picked up there. Taking a job moves it into
+//! the consist's orders, and an order can be moved back from the orders
+//! table.
+
+use egui_extras::{Column, TableBuilder};
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::history::Command;
+use crate::i18n::I18n;
+use crate::jobs::{JOB_GOALS, JobGoal, LengthLimits, pick_jobs, spare_capacity};
+use crate::order::Order;
+use crate::order_table::{OrderFilter, SortColumn};
+use crate::station::STATIONS;
+use crate::units::{Length, Mass, Units};
+
+/// The length a limit starts at when it is first ticked.
+const DEFAULT_LIMIT: Length = Length::from_meters(300.0);
+
+#[derive(Default)]
+pub struct JobBoardWindow {
+    pub open: bool,
+    goal: JobGoal,
+    /// How the jobs are sorted and filtered. The pickup filter is the
+    /// station whose pool is shown.
+    filter: OrderFilter,
+    /// The picked jobs, as indices into the consist's `jobs`.
+    picked: Vec<usize>,
+    /// The command taking jobs or removing one from the board.
+    pub command: Option<Command>,
+}
+
+impl JobBoardWindow {
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        consist: &Consist,
+        conditions: &Conditions,
+        limits: &mut LengthLimits,
+        i18n: &I18n,
+        units: &Units,
+    ) {
+        let mut open = self.open;
+        // Jobs removed elsewhere, e.g. by undo, can't stay picked.
+        self.picked.retain(|ix| *ix < consist.jobs.len());
+        egui::Window::new(i18n.tr("job-board-heading"))
+            .id(egui::Id::new("job_board_window"))
+            .open(&mut open)
+            .default_width(700.0)
+            .show(ctx, |ui| {
+                limits_ui(ui, limits, i18n, units);
+                let spare = spare_capacity(consist, conditions, limits);
+                ui.label(match spare.length {
+                    Some(length) => i18n.tr_args(
+                        "job-board-spare-length",
+                        &[
+                            ("weight", units.mass(spare.weight)),
+                            ("length", units.length(length)),
+                        ],
+                    ),
+                    None => {
+                        i18n.tr_args("job-board-spare", &[("weight", units.mass(spare.weight))])
+                    }
+                });
+                ui.separator();
+                if consist.jobs.is_empty() {
+                    ui.label(i18n.tr("job-board-empty"));
+                    return;
+                }
+                self.stations_ui(ui, &consist.jobs, i18n);
+                self.filter.controls_ui(ui, i18n);
+                let visible = self.filter.visible(&consist.jobs);
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_label(i18n.tr("job-board-goal"))
+                        .selected_text(goal_label(i18n, self.goal))
+                        .show_ui(ui, |ui| {
+                            for goal in JOB_GOALS {
+                                ui.selectable_value(&mut self.goal, goal, goal_label(i18n, goal));
+                            }
+                        });
+                    // Only the jobs shown are weighed up, so a station's
+                    // pool can be picked from on its own.
+                    if ui.button(i18n.tr("job-board-pick")).clicked() {
+                        let shown: Vec<Order> = visible
+                            .iter()
+                            .filter_map(|ix| consist.jobs.get(*ix))
+                            .cloned()
+                            .collect();
+                        self.picked = pick_jobs(&shown, &spare, self.goal)
+                            .into_iter()
+                            .filter_map(|ix| visible.get(ix).copied())
+                            .collect();
+                        self.picked.sort_unstable();
+                    }
+                });
+                self.table_ui(ui, consist, &visible, i18n, units);
+                ui.separator();
+
+                let picked = self.picked.iter().filter_map(|ix| consist.jobs.get(*ix));
+                let (weight, length, payout) =
+                    picked
+                        .clone()
+                        .fold((Mass::ZERO, Length::ZERO, 0u64), |(w, l, p), job| {
+                            (
+                                w + job.weight,
+                                l + job.length,
+                                p + u64::from(job.payout.unwrap_or_default()),
+                            )
+                        });
+                ui.label(i18n.tr_args(
+                    "job-board-picked",
+                    &[
+                        ("weight", units.mass(weight)),
+                        ("length", units.length(length)),
+                        ("payout", payout.to_string()),
+                    ],
+                ));
+                if !spare.fits_all(picked) {
+                    ui.colored_label(ui.visuals().error_fg_color, i18n.tr("job-board-too-much"));
+                }
+                let take = i18n.tr_args(
+                    "job-board-take",
+                    &[("count", self.picked.len().to_string())],
+                );
+                if ui
+                    .add_enabled(!self.picked.is_empty(), egui::Button::new(take))
+                    .clicked()
+                {
+                    self.command = Some(take_jobs(consist, &self.picked));
+                    self.picked.clear();
+                }
+            });
+        self.open = open;
+    }
+
+    // A tab for each station with jobs waiting, and one for all of them.
+    fn stations_ui(&mut self, ui: &mut egui::Ui, jobs: &[Order], i18n: &I18n) {
+        ui.horizontal_wrapped(|ui| {
+            let all = i18n.tr_args(
+                "job-board-all-stations",
+                &[("count", jobs.len().to_string())],
+            );
+            if ui
+                .selectable_label(self.filter.pickup.is_none(), all)
+                .clicked()
+            {
+                self.filter.pickup = None;
+            }
+            for station in STATIONS {
+                let count = jobs.iter().filter(|j| j.pickup_station == station).count();
+                if count == 0 {
+                    continue;
+                }
+                let label = format!("{} ({count})", station.to_abbrev());
+                if ui
+                    .selectable_label(self.filter.pickup == Some(station), label)
+                    .on_hover_text(i18n.station(station))
+                    .clicked()
+                {
+                    self.filter.pickup = Some(station);
+                }
+            }
+        });
+    }
+
+    // The jobs shown, each with a checkbox to pick it and buttons to take
+    // it straight away or take it off the board.
+    fn table_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        consist: &Consist,
+        visible: &[usize],
+        i18n: &I18n,
+        units: &Units,
+    ) {
+        TableBuilder::new(ui)
+            .id_salt("job_board_table")
+            .striped(true)
+            .max_scroll_height(300.0)
+            .columns(Column::auto().resizable(false), 11)
+            .header(20.0, |mut header| {
+                header.col(|_ui| {});
+                for (column, key) in [
+                    (SortColumn::Name, "column-order-name"),
+                    (SortColumn::Cars, "job-board-cars"),
+                    (SortColumn::Weight, "column-weight"),
+                    (SortColumn::Length, "column-length"),
+                    (SortColumn::PickupStation, "column-pickup-station"),
+                    (SortColumn::PickupTrack, "column-pickup-track"),
+                    (SortColumn::DropoffStation, "column-dropoff-station"),
+                    (SortColumn::DropoffTrack, "column-dropoff-track"),
+                    (SortColumn::Payout, "job-board-payout"),
+                ] {
+                    header.col(|ui| {
+                        let label = i18n.tr(key);
+                        self.filter.header_ui(ui, i18n, column, &label);
+                    });
+                }
+                header.col(|_ui| {});
+            })
+            .body(|body| {
+                body.rows(24.0, visible.len(), |mut row| {
+                    let Some((ix, job)) = visible
+                        .get(row.index())
+                        .and_then(|ix| Some((*ix, consist.jobs.get(*ix)?)))
+                    else {
+                        return;
+                    };
+                    row.col(|ui| {
+                        let mut picked = self.picked.contains(&ix);
+                        if ui.checkbox(&mut picked, "").changed() {
+                            if picked {
+                                self.picked.push(ix);
+                                self.picked.sort_unstable();
+                            } else {
+                                self.picked.retain(|p| *p != ix);
+                            }
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.label(&job.name);
+                    });
+                    row.col(|ui| {
+                        ui.label(job.car_count.to_string());
+                    });
+                    row.col(|ui| {
+                        ui.label(units.mass(job.weight));
+                    });
+                    row.col(|ui| {
+                        ui.label(units.length(job.length));
+                    });
+                    row.col(|ui| {
+                        ui.label(job.pickup_station.to_abbrev())
+                            .on_hover_text(i18n.station(job.pickup_station));
+                    });
+                    row.col(|ui| {
+                        ui.label(&job.pickup_track);
+                    });
+                    row.col(|ui| {
+                        ui.label(job.dropoff_station.to_abbrev())
+                            .on_hover_text(i18n.station(job.dropoff_station));
+                    });
+                    row.col(|ui| {
+                        ui.label(&job.dropoff_track);
+                    });
+                    row.col(|ui| {
+                        ui.label(
+                            job.payout
+                                .map_or_else(|| "-".to_owned(), |payout| format!("${payout}")),
+                        );
+                    });
+                    row.col(|ui| {
+                        if ui.button(i18n.tr("job-board-take-one")).clicked() {
+                            self.command = Some(take_jobs(consist, &[ix]));
+                            self.picked.clear();
+                        }
+                        if ui.button(i18n.tr("job-board-remove")).clicked() {
+                            self.command = Some(Command::DeleteJob {
+                                index: ix,
+                                job: job.clone(),
+                            });
+                            self.picked.clear();
+                        }
+                    });
+                });
+            });
+    }
+}
+
+fn goal_label(i18n: &I18n, goal: JobGoal) -> String {
+    i18n.tr(match goal {
+        JobGoal::Payout => "job-board-goal-payout",
+        JobGoal::Cars => "job-board-goal-cars",
+    })
+}
+
+// The length license and the shortest track at each station, each only
+// applying when ticked.
+fn limits_ui(ui: &mut egui::Ui, limits: &mut LengthLimits, i18n: &I18n, units: &Units) {
+    egui::Grid::new("job_board_limits")
+        .num_columns(2)
+        .show(ui, |ui| {
+            limit_ui(
+                ui,
+                &i18n.tr("job-board-license"),
+                &mut limits.license,
+                units,
+            );
+        });
+    egui::CollapsingHeader::new(i18n.tr("job-board-track"))
+        .id_salt("job_board_tracks")
+        .show(ui, |ui| {
+            egui::Grid::new("job_board_tracks")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    for station in STATIONS {
+                        let mut track = limits.tracks.get(&station).copied();
+                        limit_ui(ui, &i18n.station(station), &mut track, units);
+                        match track {
+                            Some(track) => limits.tracks.insert(station, track),
+                            None => limits.tracks.remove(&station),
+                        };
+                    }
+                });
+        });
+}
+
+// A ticked limit with its length, as a row of a grid.
+fn limit_ui(ui: &mut egui::Ui, label: &str, limit: &mut Option<Length>, units: &Units) {
+    let mut set = limit.is_some();
+    if ui.checkbox(&mut set, label).changed() {
+        *limit = set.then(|| limit.unwrap_or(DEFAULT_LIMIT));
+    }
+    if let Some(length) = limit {
+        let mut value = length.in_unit(units.length);
+        if ui
+            .add(
+                egui::DragValue::new(&mut value)
+                    .range(0.0..=f32::MAX)
+                    .suffix(format!(" {}", units.length.symbol())),
+            )
+            .changed()
+        {
+            *length = Length::from_unit(value, units.length);
+        }
+    }
+    ui.end_row();
+}
+
+// Moves the picked jobs to the end of the orders, keeping their order on
+// the board, as one undoable step.
+fn take_jobs(consist: &Consist, picked: &[usize]) -> Command {
+    let first = consist.orders.len();
+    Command::Batch(
+        picked
+            .iter()
+            .enumerate()
+            .map(|(taken, job)| Command::TakeJob {
+                job: job - taken,
+                order: first + taken,
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,258 @@
+//! Picking which jobs on the job board to take.
+//!
+//! The train can only take so much more: its locomotives have to pull the
+//! extra weight up every leg of its route in the chosen conditions, and the
+//! longer train has to stay within the player's length license and fit the
+//! tracks at the stations it works. [`pick_jobs`] finds the jobs worth the
+//! most within those limits.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::order::Order;
+use crate::route::{legs, longest_load, planned_route};
+use crate::station::Station;
+use crate::units::{Length, Mass};
+
+/// The most jobs the search weighs up; beyond this only the most valuable
+/// ones are considered.
+pub const MAX_CANDIDATES: usize = 20;
+
+/// What the picked jobs should be worth the most of.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum JobGoal {
+    #[default]
+    Payout,
+    Cars,
+}
+
+impl Display for JobGoal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let goal_str = match self {
+            Self::Payout => "Highest payout",
+            Self::Cars => "Most cars",
+        };
+        write!(f, "{goal_str}")
+    }
+}
+
+pub const JOB_GOALS: [JobGoal; 2] = [JobGoal::Payout, JobGoal::Cars];
+
+impl JobGoal {
+    /// What a job is worth toward the goal. Jobs without a known payout are
+    /// worth nothing toward the highest payout.
+    pub fn value(self, job: &Order) -> u64 {
+        match self {
+            Self::Payout => u64::from(job.payout.unwrap_or_default()),
+            Self::Cars => u64::from(job.car_count),
+        }
+    }
+}
+
+/// Limits on the length of the train, as the player set them. A limit that
+/// isn't set doesn't apply.
+#[derive(Clone, Default, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct LengthLimits {
+    /// The longest train the player's length license allows.
+    pub license: Option<Length>,
+    /// The shortest track the train has to fit on at each station, for the
+    /// stations the player has set one for.
+    pub tracks: BTreeMap<Station, Length>,
+}
+
+impl LengthLimits {
+    /// The longest the train may be at the station, if anything limits it.
+    pub fn max_at(&self, station: Station) -> Option<Length> {
+        shorter(self.license, self.tracks.get(&station).copied())
+    }
+}
+
+// The shorter of two limits, where `None` is no limit.
+fn shorter(a: Option<Length>, b: Option<Length>) -> Option<Length> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b < a { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// How much more the train can take on.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Spare {
+    pub weight: Mass,
+    /// How much longer the train may get anywhere on its route, or `None`
+    /// when its length isn't limited.
+    pub length: Option<Length>,
+    /// How much longer the train may get at stations with a track length
+    /// set, which limits the jobs picked up or dropped off there.
+    pub at_stations: BTreeMap<Station, Length>,
+}
+
+impl Spare {
+    /// How much longer the train may get to work a job.
+    fn length_for(&self, job: &Order) -> Option<Length> {
+        [job.pickup_station, job.dropoff_station]
+            .into_iter()
+            .map(|station| self.at_stations.get(&station).copied())
+            .fold(self.length, shorter)
+    }
+
+    fn fits(&self, weight: Mass, length: Length, room: Option<Length>) -> bool {
+        weight <= self.weight && room.is_none_or(|room| length <= room)
+    }
+
+    /// Whether the train can take all the jobs at once, fitting the tracks
+    /// at every station they are picked up or dropped off at.
+    pub fn fits_all<'a>(&self, jobs: impl IntoIterator<Item = &'a Order>) -> bool {
+        let (weight, length, room) = jobs.into_iter().fold(
+            (Mass::ZERO, Length::ZERO, self.length),
+            |(weight, length, room), job| {
+                (
+                    weight + job.weight,
+                    length + job.length,
+                    shorter(room, self.length_for(job)),
+                )
+            },
+        );
+        self.fits(weight, length, room)
+    }
+}
+
+/// How much more the train can take on while working its planned route.
+///
+/// The weight is what the locomotives can pull beyond the train on its
+/// tightest leg: the train as it is now on the grade in the conditions, or
+/// any leg of the route on its own ruling grade. The length is the longest
+/// the train may be at the stations it works, less the locomotives and the
+/// longest the cars get.
+pub fn spare_capacity(consist: &Consist, conditions: &Conditions, limits: &LengthLimits) -> Spare {
+    let summary = consist.summary(conditions.coupler_limit);
+    let now = consist.supported_weight(conditions) - summary.total_weight;
+    let weight = legs(consist, conditions)
+        .iter()
+        .map(|leg| leg.supported - leg.weight)
+        .fold(
+            now,
+            |tightest, margin| {
+                if margin < tightest { margin } else { tightest }
+            },
+        );
+    let used = summary.loco_length + longest_load(consist);
+    let length = planned_route(&consist.orders)
+        .into_iter()
+        .map(|station| limits.tracks.get(&station).copied())
+        .fold(limits.license, shorter);
+    Spare {
+        weight,
+        length: length.map(|max| max - used),
+        at_stations: limits
+            .tracks
+            .iter()
+            .map(|(station, track)| (*station, *track - used))
+            .collect(),
+    }
+}
+
+/// The jobs worth the most toward the goal that fit in the spare capacity
+/// together, as indices into `jobs` in ascending order.
+///
+/// Every picked job is counted as on the train at once, so the pick is safe
+/// whatever order the jobs are worked in.
+pub fn pick_jobs(jobs: &[Order], spare: &Spare, goal: JobGoal) -> Vec<usize> {
+    let mut candidates: Vec<usize> = jobs
+        .iter()
+        .enumerate()
+        .filter(|(_, job)| {
+            goal.value(job) > 0 && spare.fits(job.weight, job.length, spare.length_for(job))
+        })
+        .map(|(ix, _)| ix)
+        .collect();
+    candidates.sort_by_key(|ix| Reverse(jobs.get(*ix).map_or(0, |job| goal.value(job))));
+    candidates.truncate(MAX_CANDIDATES);
+
+    let search = Search {
+        jobs,
+        candidates: &candidates,
+        spare,
+        goal,
+    };
+    let mut picked = Vec::new();
+    let mut best = (0, Vec::new());
+    let taken = Taken {
+        weight: Mass::ZERO,
+        length: Length::ZERO,
+        room: spare.length,
+        value: 0,
+    };
+    search.extend(0, taken, &mut picked, &mut best);
+    let mut best = best.1;
+    best.sort_unstable();
+    best
+}
+
+/// A branch and bound search over which of the candidates to take.
+struct Search<'a> {
+    jobs: &'a [Order],
+    /// Indices into `jobs`, most valuable first.
+    candidates: &'a [usize],
+    spare: &'a Spare,
+    goal: JobGoal,
+}
+
+/// What the jobs picked so far add up to.
+#[derive(Clone, Copy)]
+struct Taken {
+    weight: Mass,
+    length: Length,
+    /// How much longer the train may get at the tightest station they use.
+    room: Option<Length>,
+    value: u64,
+}
+
+impl Search<'_> {
+    fn value(&self, ix: usize) -> u64 {
+        self.jobs.get(ix).map_or(0, |job| self.goal.value(job))
+    }
+
+    // Decides on the candidates from `next` on, given those already
+    // `picked`, and keeps the most valuable pick found in `best`.
+    fn extend(
+        &self,
+        next: usize,
+        taken: Taken,
+        picked: &mut Vec<usize>,
+        best: &mut (u64, Vec<usize>),
+    ) {
+        if taken.value > best.0 {
+            *best = (taken.value, picked.clone());
+        }
+        let rest = self.candidates.get(next..).unwrap_or_default();
+        // Even taking everything left can't beat the best pick.
+        if taken.value + rest.iter().map(|ix| self.value(*ix)).sum::<u64>() <= best.0 {
+            return;
+        }
+        let Some((&ix, _)) = rest.split_first() else {
+            return;
+        };
+        if let Some(job) = self.jobs.get(ix) {
+            let with_job = Taken {
+                weight: taken.weight + job.weight,
+                length: taken.length + job.length,
+                room: shorter(taken.room, self.spare.length_for(job)),
+                value: taken.value + self.value(ix),
+            };
+            if self
+                .spare
+                .fits(with_job.weight, with_job.length, with_job.room)
+            {
+                picked.push(ix);
+                self.extend(next + 1, with_job, picked, best);
+                picked.pop();
+            }
+        }
+        self.extend(next + 1, taken, picked, best);
+    }
+}
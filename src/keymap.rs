@@ -0,0 +1,296 @@
+//! The actions the app can be driven with from the keyboard, and the
+//! configurable shortcuts bound to them.
+
+use std::fmt::{self, Display, Formatter};
+
+use egui::{Key, KeyboardShortcut, Modifiers};
+
+use crate::i18n::I18n;
+
+/// Something the user can do from a shortcut or the command palette.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum Action {
+    AddOrder,
+    AddLocomotive,
+    PasteJobs,
+    EditSelected,
+    DeleteSelected,
+    MoveUp,
+    MoveDown,
+    SelectPrevious,
+    SelectNext,
+    TogglePower,
+    Undo,
+    Redo,
+    StationMap,
+    Trip,
+    Checks,
+    SuggestPower,
+    JobBoard,
+    Shortcuts,
+    CommandPalette,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let action_str = match self {
+            Self::AddOrder => "Add order",
+            Self::AddLocomotive => "Add locomotive/car",
+            Self::PasteJobs => "Paste jobs",
+            Self::EditSelected => "Edit selected order",
+            Self::DeleteSelected => "Delete selected",
+            Self::MoveUp => "Move selected up",
+            Self::MoveDown => "Move selected down",
+            Self::SelectPrevious => "Select previous",
+            Self::SelectNext => "Select next",
+            Self::TogglePower => "Toggle power of selected locomotive",
+            Self::Undo => "Undo",
+            Self::Redo => "Redo",
+            Self::StationMap => "Show/hide station map",
+            Self::Trip => "Show/hide trip",
+            Self::Checks => "Show/hide consist checks",
+            Self::SuggestPower => "Suggest power",
+            Self::JobBoard => "Show/hide job board",
+            Self::Shortcuts => "Keyboard shortcuts",
+            Self::CommandPalette => "Command palette",
+        };
+        write!(f, "{action_str}")
+    }
+}
+
+pub const ACTIONS: [Action; 19] = [
+    Action::AddOrder,
+    Action::AddLocomotive,
+    Action::PasteJobs,
+    Action::EditSelected,
+    Action::DeleteSelected,
+    Action::MoveUp,
+    Action::MoveDown,
+    Action::SelectPrevious,
+    Action::SelectNext,
+    Action::TogglePower,
+    Action::Undo,
+    Action::Redo,
+    Action::StationMap,
+    Action::Trip,
+    Action::Checks,
+    Action::SuggestPower,
+    Action::JobBoard,
+    Action::Shortcuts,
+    Action::CommandPalette,
+];
+
+/// A shortcut bound to an action.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Binding {
+    pub action: Action,
+    pub shortcut: KeyboardShortcut,
+}
+
+/// The shortcuts bound to each action. An action has at most one shortcut.
+#[derive(Clone, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Keymap {
+    #[serde(deserialize_with = "known_bindings")]
+    bindings: Vec<Binding>,
+}
+
+// Keymaps saved by an older version can bind actions that have since been
+// removed. Those bindings are dropped rather than failing to load the whole
+// app state.
+fn known_bindings<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Binding>, D::Error> {
+    use serde::Deserialize as _;
+    use serde::de::value::StrDeserializer;
+
+    struct ActionName(String);
+
+    impl<'de> serde::Deserialize<'de> for ActionName {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct NameVisitor;
+
+            impl serde::de::Visitor<'_> for NameVisitor {
+                type Value = ActionName;
+
+                fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, "an action name")
+                }
+
+                fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<ActionName, E> {
+                    Ok(ActionName(name.to_owned()))
+                }
+            }
+
+            deserializer.deserialize_identifier(NameVisitor)
+        }
+    }
+
+    #[derive(serde::Deserialize)]
+    struct StoredBinding {
+        action: ActionName,
+        shortcut: KeyboardShortcut,
+    }
+
+    let stored = Vec::<StoredBinding>::deserialize(deserializer)?;
+    Ok(stored
+        .into_iter()
+        .filter_map(|b| {
+            let name = StrDeserializer::<serde::de::value::Error>::new(&b.action.0);
+            Action::deserialize(name).ok().map(|action| Binding {
+                action,
+                shortcut: b.shortcut,
+            })
+        })
+        .collect())
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bind = |action, modifiers, key| Binding {
+            action,
+            shortcut: KeyboardShortcut::new(modifiers, key),
+        };
+        Self {
+            bindings: vec![
+                bind(Action::AddOrder, Modifiers::COMMAND, Key::N),
+                bind(Action::AddLocomotive, Modifiers::COMMAND, Key::L),
+                bind(
+                    Action::PasteJobs,
+                    Modifiers::COMMAND | Modifiers::SHIFT,
+                    Key::V,
+                ),
+                bind(Action::EditSelected, Modifiers::NONE, Key::Enter),
+                bind(Action::DeleteSelected, Modifiers::NONE, Key::Delete),
+                bind(Action::MoveUp, Modifiers::ALT, Key::ArrowUp),
+                bind(Action::MoveDown, Modifiers::ALT, Key::ArrowDown),
+                bind(Action::SelectPrevious, Modifiers::NONE, Key::ArrowUp),
+                bind(Action::SelectNext, Modifiers::NONE, Key::ArrowDown),
+                bind(Action::TogglePower, Modifiers::NONE, Key::P),
+                bind(Action::Undo, Modifiers::COMMAND, Key::Z),
+                bind(Action::Redo, Modifiers::COMMAND | Modifiers::SHIFT, Key::Z),
+                bind(Action::StationMap, Modifiers::COMMAND, Key::M),
+                bind(Action::Trip, Modifiers::COMMAND, Key::T),
+                bind(Action::Checks, Modifiers::COMMAND, Key::J),
+                bind(Action::SuggestPower, Modifiers::COMMAND, Key::G),
+                bind(Action::JobBoard, Modifiers::COMMAND, Key::B),
+                bind(Action::CommandPalette, Modifiers::COMMAND, Key::K),
+            ],
+        }
+    }
+}
+
+// Shortcuts with more modifiers are checked first, since egui lets a
+// shortcut match even when extra modifiers are held.
+fn modifier_count(modifiers: Modifiers) -> u8 {
+    u8::from(modifiers.alt)
+        + u8::from(modifiers.shift)
+        + u8::from(modifiers.command || modifiers.ctrl || modifiers.mac_cmd)
+}
+
+impl Keymap {
+    pub fn shortcut(&self, action: Action) -> Option<KeyboardShortcut> {
+        self.bindings
+            .iter()
+            .find(|b| b.action == action)
+            .map(|b| b.shortcut)
+    }
+
+    /// Binds the shortcut to the action, taking it away from any other action.
+    pub fn bind(&mut self, action: Action, shortcut: KeyboardShortcut) {
+        self.bindings
+            .retain(|b| b.action != action && b.shortcut != shortcut);
+        self.bindings.push(Binding { action, shortcut });
+    }
+
+    pub fn unbind(&mut self, action: Action) {
+        self.bindings.retain(|b| b.action != action);
+    }
+
+    /// Consumes the first pressed shortcut and returns the action bound to it.
+    pub fn consume(&self, ctx: &egui::Context) -> Option<Action> {
+        let mut bindings = self.bindings.clone();
+        bindings.sort_by_key(|b| std::cmp::Reverse(modifier_count(b.shortcut.modifiers)));
+        ctx.input_mut(|i| {
+            bindings
+                .iter()
+                .find(|b| i.consume_shortcut(&b.shortcut))
+                .map(|b| b.action)
+        })
+    }
+}
+
+/// A window listing every action's shortcut, where they can be rebound.
+#[derive(Default)]
+pub struct ShortcutsWindow {
+    pub open: bool,
+    /// The action waiting for the user to press its new shortcut.
+    recording: Option<Action>,
+}
+
+impl ShortcutsWindow {
+    pub fn show(&mut self, ctx: &egui::Context, keymap: &mut Keymap, i18n: &I18n) {
+        if let Some(action) = self.recording {
+            let pressed = ctx.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    egui::Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } => Some(KeyboardShortcut::new(*modifiers, *key)),
+                    _ => None,
+                })
+            });
+            if let Some(shortcut) = pressed {
+                if shortcut.logical_key != Key::Escape {
+                    keymap.bind(action, shortcut);
+                }
+                self.recording = None;
+                ctx.input_mut(|i| i.consume_shortcut(&shortcut));
+            }
+        }
+
+        let mut open = self.open;
+        egui::Window::new(i18n.tr("shortcuts-heading"))
+            .id(egui::Id::new("shortcuts_window"))
+            .open(&mut open)
+            .show(ctx, |ui| {
+                egui::Grid::new("shortcuts_grid")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for action in ACTIONS {
+                            ui.label(i18n.action(action));
+                            let text = if self.recording == Some(action) {
+                                i18n.tr("shortcuts-recording")
+                            } else {
+                                keymap
+                                    .shortcut(action)
+                                    .map_or_else(String::new, |s| ctx.format_shortcut(&s))
+                            };
+                            ui.label(text);
+                            if ui.button(i18n.tr("shortcuts-set")).clicked() {
+                                self.recording = Some(action);
+                            }
+                            if ui.button(i18n.tr("shortcuts-clear")).clicked() {
+                                keymap.unbind(action);
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+                if ui.button(i18n.tr("shortcuts-reset")).clicked() {
+                    *keymap = Keymap::default();
+                    self.recording = None;
+                }
+            });
+        self.open = open;
+        if !self.open {
+            self.recording = None;
+        }
+    }
+
+    /// Whether the window is waiting for a key press, so shortcuts shouldn't fire.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+}
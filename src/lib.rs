@@ -1,7 +1,31 @@
 mod app;
-mod locomotive;
-mod order;
-mod station;
+pub mod car;
+mod checks_window;
+pub mod conditions;
+pub mod consist;
+mod consist_strip;
+pub mod grades;
+pub mod hazmat;
+pub mod history;
+pub mod i18n;
+mod job_board;
+pub mod jobs;
+pub mod keymap;
+pub mod locomotive;
+pub mod order;
+pub mod order_table;
+pub mod palette;
+pub mod paste_jobs;
+pub mod power;
+mod power_window;
+pub mod route;
+pub mod rules;
+pub mod save;
+pub mod speed;
+pub mod station;
+mod station_map;
 mod toggle_switch;
+pub mod trip;
+pub mod units;
 
 pub use app::ConsistManagerApp;
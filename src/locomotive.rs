@@ -2,43 +2,87 @@ use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
 use std::sync::OnceLock;
 
+use crate::consist::Rating;
+use crate::units::{Length, Mass};
+
 #[derive(Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct LocomotiveInfo {
     pub loco: Locomotive,
-    pub weight: f32,
-    pub length: f32,
+    pub weight: Mass,
+    pub length: Length,
     pub zero_grade_t: u16,
     pub two_grade_t: u16,
     pub rain_grade_t: u16,
     pub has_power: bool,
     pub powered: bool,
+    /// Where the locomotive is coupled in the train.
+    #[serde(default)]
+    pub placement: Placement,
+    /// Whether the locomotive runs tender or cab first.
+    #[serde(default)]
+    pub reversed: bool,
 }
 
 impl LocomotiveInfo {
+    /// Takes the weight in tonnes and the length in millimeters, as given in
+    /// the game's data.
     pub fn new(
         loco: Locomotive,
-        weight: f32,
-        length: f32,
+        weight_t: f32,
+        length_mm: f32,
         zero_grade_t: u16,
         two_grade_t: u16,
         rain_grade_t: u16,
         powered: bool,
     ) -> Self {
-        let length = length / 1000.0;
         Self {
             loco,
-            weight,
-            length,
+            weight: Mass::from_tonnes(weight_t),
+            length: Length::from_millimeters(length_mm),
             zero_grade_t,
             two_grade_t,
             rain_grade_t,
             has_power: powered,
             powered,
+            placement: Placement::Head,
+            reversed: false,
+        }
+    }
+
+    /// The fastest the locomotive may run the way it is facing, in km/h.
+    pub fn max_speed_kmh(&self) -> u16 {
+        if self.reversed {
+            self.loco.reverse_max_speed_kmh()
+        } else {
+            self.loco.max_speed_kmh()
         }
     }
+
+    /// The weight in tonnes the locomotive can pull in a rated condition.
+    pub fn rating(&self, rating: Rating) -> u16 {
+        match rating {
+            Rating::ZeroGrade => self.zero_grade_t,
+            Rating::TwoGrade => self.two_grade_t,
+            Rating::TwoGradeRain => self.rain_grade_t,
+        }
+    }
+}
+
+/// Where a locomotive is coupled relative to the cuts of cars on the train.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum Placement {
+    /// Leading the train.
+    #[default]
+    Head,
+    /// Mid-train, behind the given number of cuts.
+    AfterCut(u16),
+    /// Pushing at the end of the train.
+    Rear,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, Hash)]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, serde::Deserialize, serde::Serialize, Hash,
+)]
 pub enum Locomotive {
     DE2,
     S060,
@@ -70,6 +114,55 @@ impl Display for Locomotive {
     }
 }
 
+impl Locomotive {
+    /// The fastest the locomotive may run forward, in km/h.
+    pub fn max_speed_kmh(self) -> u16 {
+        match self {
+            Self::DE2 => 80,
+            Self::S060 => 60,
+            Self::DM3 => 70,
+            Self::DH4 | Self::DE6 | Self::DE6Slug | Self::Caboose => 120,
+            Self::S282 => 100,
+            Self::BE2 => 20,
+            Self::DM1U => 40,
+        }
+    }
+
+    /// The fastest the locomotive may run in reverse, in km/h. Steam
+    /// locomotives have to go much slower tender first.
+    pub fn reverse_max_speed_kmh(self) -> u16 {
+        match self {
+            Self::S060 => 45,
+            Self::S282 => 50,
+            _ => self.max_speed_kmh(),
+        }
+    }
+
+    /// Roughly what an hour of running the locomotive costs in fuel or coal
+    /// and water, in dollars.
+    ///
+    /// These are estimates, not the game's prices, so the app labels
+    /// anything ranked by them as an estimate.
+    pub fn running_cost(self) -> u16 {
+        match self {
+            Self::DE2 => 60,
+            Self::S060 => 80,
+            Self::DM3 => 70,
+            Self::DH4 => 120,
+            Self::S282 => 200,
+            Self::DE6 => 220,
+            Self::BE2 => 10,
+            Self::DM1U => 30,
+            Self::DE6Slug | Self::Caboose => 0,
+        }
+    }
+
+    /// Whether this is a steam locomotive, which is slower running in reverse.
+    pub fn is_steam(self) -> bool {
+        matches!(self, Self::S060 | Self::S282)
+    }
+}
+
 pub const LOCO_LIST: [Locomotive; 10] = [
     Locomotive::DE2,
     Locomotive::S060,
@@ -125,7 +218,10 @@ pub fn locomotives() -> &'static HashMap<Locomotive, LocomotiveInfo> {
         );
         l.insert(
             Locomotive::Caboose,
-            LocomotiveInfo::new(Locomotive::Caboose, 22.0, 13200.0, 0, 0, 0, false),
+            LocomotiveInfo {
+                placement: Placement::Rear,
+                ..LocomotiveInfo::new(Locomotive::Caboose, 22.0, 13200.0, 0, 0, 0, false)
+            },
         );
         l
     })
@@ -1,14 +1,232 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::car::{CAR_TYPES, CarType};
+use crate::hazmat::{HAZMAT_CLASSES, HazmatClass};
+use crate::i18n::I18n;
+use crate::speed::{HAZMAT_SPEED_KMH, SpeedLimit, SpeedLimiter};
 use crate::station::{STATIONS, Station};
+use crate::units::{Length, Mass, Units};
+
+/// Where an order's cars are: waiting at the pickup, on the train, or dropped off.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub enum OrderStatus {
+    #[default]
+    Planned,
+    PickedUp,
+    Delivered,
+}
+
+impl Display for OrderStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            Self::Planned => "Planned",
+            Self::PickedUp => "On train",
+            Self::Delivered => "Delivered",
+        };
+        write!(f, "{status_str}")
+    }
+}
+
+impl OrderStatus {
+    // Orders saved before they had a status were all counted as on the train.
+    fn legacy() -> Self {
+        Self::PickedUp
+    }
+
+    /// The status as shown in the UI's language.
+    pub fn translated(self, i18n: &I18n) -> String {
+        i18n.tr(match self {
+            Self::Planned => "status-planned",
+            Self::PickedUp => "status-picked-up",
+            Self::Delivered => "status-delivered",
+        })
+    }
+}
+
+pub const ORDER_STATUSES: [OrderStatus; 3] = [
+    OrderStatus::Planned,
+    OrderStatus::PickedUp,
+    OrderStatus::Delivered,
+];
 
 #[derive(Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Order {
     pub name: String,
-    pub weight: f32,
-    pub length: f32,
+    #[serde(default)]
+    pub job_id: String,
+    #[serde(default)]
+    pub car_count: u16,
+    /// The type of the cars in this cut, when they are all the same.
+    #[serde(default)]
+    pub car_type: Option<CarType>,
+    #[serde(default)]
+    pub cargo: String,
+    /// The class of hazardous cargo the cars carry, if any.
+    #[serde(default)]
+    pub hazmat: Option<HazmatClass>,
+    pub weight: Mass,
+    pub length: Length,
     pub pickup_station: Station,
     pub pickup_track: String,
     pub dropoff_station: Station,
     pub dropoff_track: String,
+    #[serde(default = "OrderStatus::legacy")]
+    pub status: OrderStatus,
+    /// A speed limit given on the job itself, in km/h.
+    #[serde(default)]
+    pub speed_limit_kmh: Option<u16>,
+    /// What the job pays on delivery, in dollars, if known.
+    #[serde(default)]
+    pub payout: Option<u32>,
+}
+
+impl Order {
+    /// The order's hazmat class, or the one its cargo implies for orders
+    /// saved without one.
+    pub fn hazmat_class(&self) -> Option<HazmatClass> {
+        self.hazmat.or_else(|| HazmatClass::from_cargo(&self.cargo))
+    }
+
+    /// The fastest the order's cars may run, from the car type, the cargo
+    /// and the order's own limit, whichever is lowest.
+    pub fn speed_limit(&self) -> Option<SpeedLimit> {
+        let car_type = self.car_type.map(|c| SpeedLimit {
+            kmh: c.info().max_speed_kmh,
+            limiter: SpeedLimiter::CarType(c),
+        });
+        let hazmat = self.hazmat_class().map(|_| SpeedLimit {
+            kmh: HAZMAT_SPEED_KMH,
+            limiter: SpeedLimiter::Hazmat(self.name.clone()),
+        });
+        let own = self.speed_limit_kmh.map(|kmh| SpeedLimit {
+            kmh,
+            limiter: SpeedLimiter::Order(self.name.clone()),
+        });
+        [car_type, hazmat, own]
+            .into_iter()
+            .flatten()
+            .min_by_key(|limit| limit.kmh)
+    }
+}
+
+/// The heaviest order the form accepts.
+const MAX_ORDER_WEIGHT: Mass = Mass::from_tonnes(5000.0);
+/// The longest order the form accepts.
+const MAX_ORDER_LENGTH: Length = Length::from_meters(2000.0);
+/// The most cars the form accepts in a single order.
+const MAX_CAR_COUNT: u16 = 100;
+/// The highest speed limit in km/h the form accepts.
+const MAX_SPEED_LIMIT_KMH: u16 = 200;
+/// The highest payout in dollars the form accepts.
+const MAX_PAYOUT: u32 = 1_000_000;
+
+/// Checks that a track follows the in-game naming: a yard letter, a track
+/// number and a track type letter, e.g. `B7L` or `C12S`.
+///
+/// The check is exact. The order form trims and upper-cases what's typed
+/// before checking it, see [`normalize_track`].
+pub fn is_valid_track(track: &str) -> bool {
+    let mut chars = track.chars().peekable();
+    let yard = chars.next_if(char::is_ascii_uppercase).is_some();
+    let mut digits = 0;
+    while chars.next_if(char::is_ascii_digit).is_some() {
+        digits += 1;
+    }
+    let kind = chars.next_if(char::is_ascii_uppercase).is_some();
+    yard && (1..=2).contains(&digits) && kind && chars.next().is_none()
+}
+
+/// Parses a number entered in the form and checks it lies in `0 < value <= max`.
+fn parse_in_range(text: &str, max: f32, unit: &str, i18n: &I18n) -> Result<f32, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(i18n.tr("error-required"));
+    }
+    match text.parse::<f32>() {
+        Ok(value) if value > 0.0 && value <= max => Ok(value),
+        Ok(_) => Err(i18n.tr_args(
+            "error-out-of-range",
+            &[("max", format!("{max:.0}")), ("unit", unit.to_owned())],
+        )),
+        Err(_) => Err(i18n.tr("error-not-a-number")),
+    }
+}
+
+/// A track as typed into the form, written the way the game writes it.
+pub fn normalize_track(track: &str) -> String {
+    track.trim().to_ascii_uppercase()
+}
+
+fn track_error(track: &str, i18n: &I18n) -> Option<String> {
+    if track.trim().is_empty() {
+        Some(i18n.tr("error-required"))
+    } else if !is_valid_track(&normalize_track(track)) {
+        Some(i18n.tr("error-track-format"))
+    } else {
+        None
+    }
+}
+
+fn field_error(ui: &mut egui::Ui, error: Option<&String>) {
+    if let Some(error) = error {
+        ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+}
+
+/// Problems with the fields of an [`OrderModal`], one per field.
+#[derive(Default)]
+pub struct OrderFormErrors {
+    pub name: Option<String>,
+    pub weight: Option<String>,
+    pub length: Option<String>,
+    pub car_count: Option<String>,
+    pub speed_limit: Option<String>,
+    pub payout: Option<String>,
+    pub pickup_track: Option<String>,
+    pub dropoff_track: Option<String>,
+}
+
+impl OrderFormErrors {
+    pub fn is_valid(&self) -> bool {
+        self.first().is_none()
+    }
+
+    /// The first problem found, in the order the fields are laid out.
+    pub fn first(&self) -> Option<&str> {
+        let Self {
+            name,
+            weight,
+            length,
+            car_count,
+            speed_limit,
+            payout,
+            pickup_track,
+            dropoff_track,
+        } = self;
+        [
+            name,
+            weight,
+            length,
+            car_count,
+            speed_limit,
+            payout,
+            pickup_track,
+            dropoff_track,
+        ]
+        .into_iter()
+        .find_map(Option::as_deref)
+    }
 }
 
 pub enum OrderModalMode {
@@ -19,12 +237,22 @@ pub enum OrderModalMode {
 pub struct OrderModal {
     pub modal_mode: OrderModalMode,
     pub order_name: String,
+    pub job_id: String,
+    pub car_count: String,
+    pub car_type: Option<CarType>,
+    pub cargo: String,
+    pub hazmat: Option<HazmatClass>,
+    pub speed_limit: String,
+    pub payout: String,
     pub weight: String,
     pub length: String,
     pub pickup: Station,
     pub pickup_track: String,
     pub dropoff: Station,
     pub dropoff_track: String,
+    pub status: OrderStatus,
+    /// The units the weight and length fields are entered in.
+    pub units: Units,
     pub order: Option<Order>,
     pub open: bool,
     pub index: usize,
@@ -35,12 +263,21 @@ impl OrderModal {
         Self {
             modal_mode,
             order_name: String::new(),
+            job_id: String::new(),
+            car_count: String::new(),
+            car_type: None,
+            cargo: String::new(),
+            hazmat: None,
+            speed_limit: String::new(),
+            payout: String::new(),
             weight: String::new(),
             length: String::new(),
             pickup: Station::SteelMill,
             pickup_track: String::new(),
             dropoff: Station::Harbor,
             dropoff_track: String::new(),
+            status: OrderStatus::Planned,
+            units: Units::default(),
             order: None,
             open: false,
             index: 0,
@@ -50,70 +287,146 @@ impl OrderModal {
     pub fn init_from_order(&mut self, order: &Order, index: usize) {
         let Order {
             name,
+            job_id,
+            car_count,
+            car_type,
+            cargo,
+            hazmat: _,
             weight,
             length,
             pickup_station,
             pickup_track,
             dropoff_station,
             dropoff_track,
+            status,
+            speed_limit_kmh,
+            payout,
         } = order;
         self.order_name = name.clone();
-        self.weight = weight.to_string();
-        self.length = length.to_string();
+        self.job_id = job_id.clone();
+        self.car_count = car_count.to_string();
+        self.car_type = *car_type;
+        self.cargo = cargo.clone();
+        self.hazmat = order.hazmat_class();
+        self.speed_limit = speed_limit_kmh.map_or_else(String::new, |kmh| kmh.to_string());
+        self.payout = payout.map_or_else(String::new, |payout| payout.to_string());
+        self.weight = weight.in_unit(self.units.mass).to_string();
+        self.length = length.in_unit(self.units.length).to_string();
         self.pickup = *pickup_station;
         self.pickup_track = pickup_track.clone();
         self.dropoff = *dropoff_station;
         self.dropoff_track = dropoff_track.clone();
+        self.status = *status;
         self.index = index;
     }
 
-    pub fn show(&mut self, ctx: &egui::Context) {
-        let (order_label, button_label): (String, String) = match self.modal_mode {
-            OrderModalMode::New => ("Add Order".into(), "Add".into()),
-            OrderModalMode::Edit => ("Edit Order".into(), "Edit".into()),
+    pub fn show(&mut self, ctx: &egui::Context, i18n: &I18n) {
+        let (order_label, button_label) = match self.modal_mode {
+            OrderModalMode::New => (i18n.tr("order-form-add-heading"), i18n.tr("button-add")),
+            OrderModalMode::Edit => (i18n.tr("order-form-edit-heading"), i18n.tr("button-edit")),
         };
         if self.open {
             egui::Modal::new(order_label.clone().into()).show(ctx, |ui| {
+                let errors = self.validate(i18n);
                 ui.set_width(250.0);
                 ui.heading(order_label);
-                ui.label("Order Name");
+                ui.label(i18n.tr("order-form-name"));
                 ui.text_edit_singleline(&mut self.order_name);
-                ui.label("Weight");
+                field_error(ui, errors.name.as_ref());
+                ui.label(i18n.tr("order-form-job-id"));
+                ui.text_edit_singleline(&mut self.job_id);
+                ui.label(i18n.tr_args(
+                    "order-form-weight",
+                    &[("unit", self.units.mass.symbol().to_owned())],
+                ));
                 ui.text_edit_singleline(&mut self.weight);
-                ui.label("Length");
+                field_error(ui, errors.weight.as_ref());
+                ui.label(i18n.tr_args(
+                    "order-form-length",
+                    &[("unit", self.units.length.symbol().to_owned())],
+                ));
                 ui.text_edit_singleline(&mut self.length);
+                field_error(ui, errors.length.as_ref());
+                ui.label(i18n.tr("order-form-cars"));
+                ui.text_edit_singleline(&mut self.car_count);
+                field_error(ui, errors.car_count.as_ref());
+                let unknown = i18n.tr("order-form-car-type-unknown");
+                egui::ComboBox::from_label(i18n.tr("order-form-car-type"))
+                    .selected_text(self.car_type.map_or(unknown.clone(), |c| c.to_string()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.car_type, None, unknown);
+                        for c in CAR_TYPES {
+                            ui.selectable_value(&mut self.car_type, Some(c), c.to_string());
+                        }
+                    });
+                ui.label(i18n.tr("order-form-cargo"));
+                ui.text_edit_singleline(&mut self.cargo);
+                let none = i18n.tr("order-form-hazmat-none");
+                egui::ComboBox::from_label(i18n.tr("order-form-hazmat"))
+                    .selected_text(self.hazmat.map_or(none.clone(), |c| i18n.hazmat_class(c)))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.hazmat, None, none);
+                        for class in HAZMAT_CLASSES {
+                            ui.selectable_value(
+                                &mut self.hazmat,
+                                Some(class),
+                                i18n.hazmat_class(class),
+                            );
+                        }
+                    });
+                ui.label(i18n.tr("order-form-speed-limit"));
+                ui.text_edit_singleline(&mut self.speed_limit);
+                field_error(ui, errors.speed_limit.as_ref());
+                ui.label(i18n.tr("order-form-payout"));
+                ui.text_edit_singleline(&mut self.payout);
+                field_error(ui, errors.payout.as_ref());
                 ui.separator();
-                egui::ComboBox::from_label("Pickup Station:")
+                egui::ComboBox::from_label(i18n.tr("order-form-pickup-station"))
                     .selected_text(self.pickup.to_abbrev())
                     .show_ui(ui, |ui| {
                         for s in STATIONS {
-                            let station_str = s.to_abbrev();
-                            ui.selectable_value(&mut self.pickup, s, station_str);
+                            ui.selectable_value(&mut self.pickup, s, s.to_abbrev())
+                                .on_hover_text(i18n.station(s));
                         }
                     });
-                ui.label("Pickup Track");
+                ui.label(i18n.tr("order-form-pickup-track"));
                 ui.separator();
                 ui.text_edit_singleline(&mut self.pickup_track);
-                egui::ComboBox::from_label("Dropoff Station:")
+                field_error(ui, errors.pickup_track.as_ref());
+                egui::ComboBox::from_label(i18n.tr("order-form-dropoff-station"))
                     .selected_text(self.dropoff.to_abbrev())
                     .show_ui(ui, |ui| {
                         for s in STATIONS {
-                            let station_str = s.to_abbrev();
-                            ui.selectable_value(&mut self.dropoff, s, station_str);
+                            ui.selectable_value(&mut self.dropoff, s, s.to_abbrev())
+                                .on_hover_text(i18n.station(s));
                         }
                     });
-                ui.label("Dropoff Track");
+                ui.label(i18n.tr("order-form-dropoff-track"));
                 ui.text_edit_singleline(&mut self.dropoff_track);
+                field_error(ui, errors.dropoff_track.as_ref());
+                ui.separator();
+                egui::ComboBox::from_label(i18n.tr("order-form-status"))
+                    .selected_text(self.status.translated(i18n))
+                    .show_ui(ui, |ui| {
+                        for status in ORDER_STATUSES {
+                            ui.selectable_value(&mut self.status, status, status.translated(i18n));
+                        }
+                    });
                 egui::Sides::new().show(
                     ui,
                     |_ui| {},
                     |ui| {
-                        if ui.button(button_label).clicked() {
-                            self.update_order();
+                        let button =
+                            ui.add_enabled(errors.is_valid(), egui::Button::new(button_label));
+                        if button.clicked()
+                            && let Some(order) = self.build_order(i18n)
+                        {
+                            self.order = Some(order);
+                            self.clear();
                             self.open = false;
                             ui.close();
                         }
-                        if ui.button("Cancel").clicked() {
+                        if ui.button(i18n.tr("button-cancel")).clicked() {
                             self.open = false;
                             ui.close();
                         }
@@ -123,20 +436,131 @@ impl OrderModal {
         }
     }
 
-    fn update_order(&mut self) {
-        self.order = Some(Order {
-            name: self.order_name.clone(),
-            weight: str::parse(&self.weight).expect("Invalid weight"),
-            length: str::parse(&self.length).expect("Invalid length"),
+    /// Checks every field of the form and reports the problem with each invalid one.
+    pub fn validate(&self, i18n: &I18n) -> OrderFormErrors {
+        let mut errors = OrderFormErrors::default();
+        if self.order_name.trim().is_empty() {
+            errors.name = Some(i18n.tr("error-required"));
+        }
+        errors.weight = self.parse_weight(i18n).err();
+        errors.length = self.parse_length(i18n).err();
+        if self.parse_car_count().is_none() {
+            errors.car_count =
+                Some(i18n.tr_args("error-car-count", &[("max", MAX_CAR_COUNT.to_string())]));
+        }
+        errors.speed_limit = self.parse_speed_limit(i18n).err();
+        errors.payout = self.parse_payout(i18n).err();
+        errors.pickup_track = track_error(&self.pickup_track, i18n);
+        errors.dropoff_track = track_error(&self.dropoff_track, i18n);
+        // The cars have to go somewhere: to another station, or to another
+        // track at the same station as in a shunting job.
+        if errors.pickup_track.is_none()
+            && errors.dropoff_track.is_none()
+            && self.pickup == self.dropoff
+            && normalize_track(&self.pickup_track) == normalize_track(&self.dropoff_track)
+        {
+            errors.dropoff_track = Some(i18n.tr("error-same-track"));
+        }
+        errors
+    }
+
+    fn parse_weight(&self, i18n: &I18n) -> Result<Mass, String> {
+        let unit = self.units.mass;
+        parse_in_range(
+            &self.weight,
+            MAX_ORDER_WEIGHT.in_unit(unit),
+            unit.symbol(),
+            i18n,
+        )
+        .map(|value| Mass::from_unit(value, unit))
+    }
+
+    fn parse_length(&self, i18n: &I18n) -> Result<Length, String> {
+        let unit = self.units.length;
+        parse_in_range(
+            &self.length,
+            MAX_ORDER_LENGTH.in_unit(unit),
+            unit.symbol(),
+            i18n,
+        )
+        .map(|value| Length::from_unit(value, unit))
+    }
+
+    fn parse_car_count(&self) -> Option<u16> {
+        match self.car_count.trim() {
+            "" => Some(0),
+            count => count.parse().ok().filter(|c| *c <= MAX_CAR_COUNT),
+        }
+    }
+
+    // An empty field means the order has no limit of its own.
+    fn parse_speed_limit(&self, i18n: &I18n) -> Result<Option<u16>, String> {
+        match self.speed_limit.trim() {
+            "" => Ok(None),
+            kmh => kmh
+                .parse()
+                .ok()
+                .filter(|kmh| (1..=MAX_SPEED_LIMIT_KMH).contains(kmh))
+                .map(Some)
+                .ok_or_else(|| {
+                    i18n.tr_args(
+                        "error-speed-limit",
+                        &[("max", MAX_SPEED_LIMIT_KMH.to_string())],
+                    )
+                }),
+        }
+    }
+
+    // An empty field means the payout isn't known.
+    fn parse_payout(&self, i18n: &I18n) -> Result<Option<u32>, String> {
+        match self.payout.trim().trim_start_matches('$') {
+            "" => Ok(None),
+            payout => payout
+                .parse()
+                .ok()
+                .filter(|payout| *payout <= MAX_PAYOUT)
+                .map(Some)
+                .ok_or_else(|| i18n.tr_args("error-payout", &[("max", MAX_PAYOUT.to_string())])),
+        }
+    }
+
+    /// Builds the order from the form, if every field is valid.
+    fn build_order(&self, i18n: &I18n) -> Option<Order> {
+        if !self.validate(i18n).is_valid() {
+            return None;
+        }
+        Some(Order {
+            name: self.order_name.trim().to_owned(),
+            job_id: self.job_id.trim().to_owned(),
+            car_count: self.parse_car_count()?,
+            car_type: self.car_type,
+            cargo: self.cargo.trim().to_owned(),
+            hazmat: self.hazmat,
+            weight: self.parse_weight(i18n).ok()?,
+            length: self.parse_length(i18n).ok()?,
             pickup_station: self.pickup,
-            pickup_track: self.pickup_track.clone(),
+            pickup_track: normalize_track(&self.pickup_track),
             dropoff_station: self.dropoff,
-            dropoff_track: self.dropoff_track.clone(),
-        });
+            dropoff_track: normalize_track(&self.dropoff_track),
+            status: self.status,
+            speed_limit_kmh: self.parse_speed_limit(i18n).ok()?,
+            payout: self.parse_payout(i18n).ok()?,
+        })
+    }
+
+    fn clear(&mut self) {
         self.order_name = String::new();
+        self.job_id = String::new();
+        self.car_count = String::new();
+        self.car_type = None;
+        self.cargo = String::new();
+        self.hazmat = None;
+        self.speed_limit = String::new();
+        self.payout = String::new();
         self.weight = String::new();
         self.length = String::new();
         self.pickup_track = String::new();
         self.dropoff_track = String::new();
+        self.status = OrderStatus::Planned;
     }
 }
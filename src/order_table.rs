@@ -0,0 +1,216 @@
+//! Sorting and filtering for the orders table and the job board.
+//!
+//! The table is drawn from a list of indices into the consist's orders, so
+//! sorting and filtering never change the orders themselves and every action
+//! on a row still refers to the right order.
+
+use std::cmp::Ordering;
+
+use crate::i18n::I18n;
+use crate::order::{ORDER_STATUSES, Order, OrderStatus};
+use crate::station::{STATIONS, Station};
+
+/// A column the orders table or job board can be sorted by.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortColumn {
+    Name,
+    Weight,
+    Length,
+    PickupStation,
+    PickupTrack,
+    DropoffStation,
+    DropoffTrack,
+    Status,
+    Cars,
+    Payout,
+}
+
+impl SortColumn {
+    fn compare(self, a: &Order, b: &Order) -> Ordering {
+        match self {
+            Self::Name => a.name.cmp(&b.name),
+            Self::Weight => a.weight.total_cmp(&b.weight),
+            Self::Length => a.length.total_cmp(&b.length),
+            Self::PickupStation => a
+                .pickup_station
+                .to_abbrev()
+                .cmp(&b.pickup_station.to_abbrev()),
+            Self::PickupTrack => a.pickup_track.cmp(&b.pickup_track),
+            Self::DropoffStation => a
+                .dropoff_station
+                .to_abbrev()
+                .cmp(&b.dropoff_station.to_abbrev()),
+            Self::DropoffTrack => a.dropoff_track.cmp(&b.dropoff_track),
+            Self::Status => a.status.cmp(&b.status),
+            Self::Cars => a.car_count.cmp(&b.car_count),
+            Self::Payout => a.payout.cmp(&b.payout),
+        }
+    }
+}
+
+/// How the orders table is currently sorted and filtered.
+#[derive(Default)]
+pub struct OrderFilter {
+    /// The column to sort by and whether the sort is ascending.
+    pub sort: Option<(SortColumn, bool)>,
+    pub search: String,
+    pub pickup: Option<Station>,
+    pub dropoff: Option<Station>,
+    /// Only orders picked up or dropped off at this station are shown.
+    pub station: Option<Station>,
+}
+
+impl OrderFilter {
+    pub fn is_sorted(&self) -> bool {
+        self.sort.is_some()
+    }
+
+    /// Whether any filter hides orders from the table.
+    pub fn is_filtering(&self) -> bool {
+        !self.search.trim().is_empty()
+            || self.pickup.is_some()
+            || self.dropoff.is_some()
+            || self.station.is_some()
+    }
+
+    pub fn matches(&self, order: &Order) -> bool {
+        let search = self.search.trim().to_lowercase();
+        let matches_search = search.is_empty()
+            || [
+                order.name.clone(),
+                order.job_id.clone(),
+                order.pickup_track.clone(),
+                order.dropoff_track.clone(),
+                format!(
+                    "{}-{}",
+                    order.pickup_station.to_abbrev(),
+                    order.pickup_track
+                ),
+                format!(
+                    "{}-{}",
+                    order.dropoff_station.to_abbrev(),
+                    order.dropoff_track
+                ),
+            ]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&search));
+        matches_search
+            && self.pickup.is_none_or(|s| order.pickup_station == s)
+            && self.dropoff.is_none_or(|s| order.dropoff_station == s)
+            && self
+                .station
+                .is_none_or(|s| order.pickup_station == s || order.dropoff_station == s)
+    }
+
+    /// The indices of the orders to show, in the order to show them.
+    pub fn visible(&self, orders: &[Order]) -> Vec<usize> {
+        let mut visible: Vec<usize> = orders
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| self.matches(order))
+            .map(|(ix, _)| ix)
+            .collect();
+        if let Some((column, ascending)) = self.sort {
+            visible.sort_by(|a, b| match (orders.get(*a), orders.get(*b)) {
+                (Some(a), Some(b)) if ascending => column.compare(a, b),
+                (Some(a), Some(b)) => column.compare(b, a),
+                _ => Ordering::Equal,
+            });
+        }
+        visible
+    }
+
+    /// The visible orders that are still to be delivered, which the orders
+    /// table lists. Delivered orders are listed on their own.
+    pub fn open_orders(&self, orders: &[Order]) -> Vec<usize> {
+        let mut visible = self.visible(orders);
+        visible.retain(|ix| {
+            orders
+                .get(*ix)
+                .is_some_and(|o| o.status != OrderStatus::Delivered)
+        });
+        visible
+    }
+
+    /// A clickable column header. Clicking cycles through sorting the column
+    /// ascending, descending and back to the consist's own order.
+    pub fn header_ui(&mut self, ui: &mut egui::Ui, i18n: &I18n, column: SortColumn, label: &str) {
+        let arrow = match self.sort {
+            Some((sorted, true)) if sorted == column => " ⏶",
+            Some((sorted, false)) if sorted == column => " ⏷",
+            _ => "",
+        };
+        let text = egui::RichText::new(format!("{label}{arrow}")).strong();
+        if ui
+            .add(egui::Button::new(text).frame(false))
+            .on_hover_text(i18n.tr("sort-hint"))
+            .clicked()
+        {
+            self.sort = match self.sort {
+                Some((sorted, true)) if sorted == column => Some((column, false)),
+                Some((sorted, false)) if sorted == column => None,
+                _ => Some((column, true)),
+            };
+        }
+    }
+
+    /// The search box and station filters shown above the table.
+    pub fn controls_ui(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(i18n.tr("filter-search"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.search)
+                    .desired_width(150.0)
+                    .hint_text(i18n.tr("filter-search-hint")),
+            );
+            ui.add_space(10.0);
+            ui.label(i18n.tr("filter-pickup"));
+            station_filter_combo(ui, i18n, "pickup_filter", &mut self.pickup);
+            ui.label(i18n.tr("filter-dropoff"));
+            station_filter_combo(ui, i18n, "dropoff_filter", &mut self.dropoff);
+            if let Some(station) = self.station {
+                ui.add_space(10.0);
+                ui.label(i18n.tr_args("filter-station", &[("station", i18n.station(station))]));
+            }
+            if self.is_filtering() && ui.button(i18n.tr("filter-clear")).clicked() {
+                self.search.clear();
+                self.pickup = None;
+                self.dropoff = None;
+                self.station = None;
+            }
+        });
+    }
+}
+
+/// A combo box for changing an order's status. Returns the newly picked status.
+pub fn status_combo(
+    ui: &mut egui::Ui,
+    i18n: &I18n,
+    id: egui::Id,
+    status: OrderStatus,
+) -> Option<OrderStatus> {
+    let mut picked = status;
+    egui::ComboBox::from_id_salt(id)
+        .width(80.0)
+        .selected_text(status.translated(i18n))
+        .show_ui(ui, |ui| {
+            for s in ORDER_STATUSES {
+                ui.selectable_value(&mut picked, s, s.translated(i18n));
+            }
+        });
+    (picked != status).then_some(picked)
+}
+
+fn station_filter_combo(ui: &mut egui::Ui, i18n: &I18n, id: &str, station: &mut Option<Station>) {
+    let any = i18n.tr("filter-any");
+    egui::ComboBox::from_id_salt(id)
+        .width(60.0)
+        .selected_text(station.map_or_else(|| any.clone(), Station::to_abbrev))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(station, None, any);
+            for s in STATIONS {
+                ui.selectable_value(station, Some(s), s.to_abbrev())
+                    .on_hover_text(i18n.station(s));
+            }
+        });
+}
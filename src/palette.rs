@@ -0,0 +1,159 @@
+//! A searchable list of every action and station, opened with a shortcut so
+//! the app can be driven without the mouse.
+
+use crate::i18n::I18n;
+use crate::keymap::{ACTIONS, Action, Keymap};
+use crate::station::{STATIONS, Station};
+
+/// Something the command palette can run.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PaletteEntry {
+    Action(Action),
+    /// Shows only the orders to or from the station.
+    Station(Station),
+}
+
+impl PaletteEntry {
+    fn label(self, i18n: &I18n) -> String {
+        match self {
+            Self::Action(action) => i18n.action(action),
+            Self::Station(station) => i18n.tr_args(
+                "palette-station",
+                &[
+                    ("station", i18n.station(station)),
+                    ("abbrev", station.to_abbrev()),
+                ],
+            ),
+        }
+    }
+}
+
+/// Scores how well the query matches the text, or returns `None` if it doesn't.
+///
+/// Every character of the query has to appear in the text in order;
+/// consecutive matches and matches at the start of a word score higher.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut previous: Option<usize> = None;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = text
+            .iter()
+            .skip(pos)
+            .position(|c| *c == q)
+            .map(|offset| pos + offset)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        let word_start = found
+            .checked_sub(1)
+            .and_then(|ix| text.get(ix))
+            .is_none_or(|c| !c.is_alphanumeric());
+        if word_start {
+            score += 3;
+        }
+        previous = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+/// The palette dialog. The chosen entry is left in `chosen` for the app to run.
+#[derive(Default)]
+pub struct CommandPalette {
+    pub open: bool,
+    pub chosen: Option<PaletteEntry>,
+    query: String,
+    highlighted: usize,
+}
+
+impl CommandPalette {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.query.clear();
+        self.highlighted = 0;
+    }
+
+    // The entries matching the query, best match first.
+    fn matches(&self, i18n: &I18n) -> Vec<PaletteEntry> {
+        let entries = ACTIONS
+            .into_iter()
+            .filter(|a| *a != Action::CommandPalette)
+            .map(PaletteEntry::Action)
+            .chain(STATIONS.into_iter().map(PaletteEntry::Station));
+        let mut scored: Vec<(u32, PaletteEntry)> = entries
+            .filter_map(|entry| fuzzy_score(&self.query, &entry.label(i18n)).map(|s| (s, entry)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, keymap: &Keymap, i18n: &I18n) {
+        if !self.open {
+            return;
+        }
+        let matches = self.matches(i18n);
+        let (up, down, enter) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            )
+        });
+        if up {
+            self.highlighted = self.highlighted.saturating_sub(1);
+        }
+        if down {
+            self.highlighted += 1;
+        }
+        self.highlighted = self.highlighted.min(matches.len().saturating_sub(1));
+
+        let modal = egui::Modal::new("Command Palette".into()).show(ctx, |ui| {
+            ui.set_width(400.0);
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .desired_width(f32::INFINITY)
+                    .hint_text(i18n.tr("palette-hint")),
+            );
+            response.request_focus();
+            if response.changed() {
+                self.highlighted = 0;
+            }
+            ui.separator();
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    for (ix, entry) in matches.iter().enumerate() {
+                        let selected = ix == self.highlighted;
+                        let row = ui.horizontal(|ui| {
+                            let label = ui.selectable_label(selected, entry.label(i18n));
+                            if let PaletteEntry::Action(action) = entry
+                                && let Some(shortcut) = keymap.shortcut(*action)
+                            {
+                                ui.weak(ctx.format_shortcut(&shortcut));
+                            }
+                            label
+                        });
+                        if selected && (up || down) {
+                            row.inner.scroll_to_me(None);
+                        }
+                        if row.inner.clicked() {
+                            self.chosen = Some(*entry);
+                        }
+                    }
+                    if matches.is_empty() {
+                        ui.label(i18n.tr("palette-nothing"));
+                    }
+                });
+        });
+
+        if enter {
+            self.chosen = matches.get(self.highlighted).copied();
+        }
+        if self.chosen.is_some() || modal.should_close() {
+            self.open = false;
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use crate::i18n::I18n;
+use crate::order::{Order, OrderFormErrors, OrderModal, OrderModalMode, OrderStatus};
+use crate::station::{STATIONS, Station};
+use crate::units::{Length, LengthUnit, Mass, MassUnit, Units};
+
+/// What a number in the pasted text turned out to describe.
+enum Quantity {
+    Cars,
+    Weight(MassUnit),
+    Length(LengthUnit),
+}
+
+#[derive(Default)]
+struct ParsedJob {
+    job_id: String,
+    car_count: Option<u16>,
+    weight: Option<Mass>,
+    length: Option<Length>,
+    payout: Option<u32>,
+    tracks: Vec<(Station, String)>,
+}
+
+impl ParsedJob {
+    fn into_order(self) -> Order {
+        let origin_station = self
+            .job_id
+            .split('-')
+            .next()
+            .and_then(Station::from_abbrev)
+            .unwrap_or(Station::SteelMill);
+        let mut tracks = self.tracks.into_iter();
+        let (pickup_station, pickup_track) = tracks
+            .next()
+            .unwrap_or_else(|| (origin_station, String::new()));
+        let (dropoff_station, dropoff_track) = tracks
+            .next()
+            .unwrap_or_else(|| (pickup_station, String::new()));
+        Order {
+            name: self.job_id.clone(),
+            job_id: self.job_id,
+            car_count: self.car_count.unwrap_or_default(),
+            car_type: None,
+            cargo: String::new(),
+            hazmat: None,
+            weight: self.weight.unwrap_or(Mass::ZERO),
+            length: self.length.unwrap_or(Length::ZERO),
+            pickup_station,
+            pickup_track,
+            dropoff_station,
+            dropoff_track,
+            status: OrderStatus::Planned,
+            speed_limit_kmh: None,
+            payout: self.payout,
+        }
+    }
+}
+
+/// Parses text copied from the job overview into orders.
+///
+/// Every job starts at its job ID (e.g. `SM-FH-12`) and runs until the next
+/// one, so jobs can be laid out one per line or as blocks. Within a job, the
+/// car count, weight and length are recognised by their unit (`5 cars`,
+/// `312.5 t`, `74.5m`) or label (`Weight: 312.5`), a dollar amount (`$4250`)
+/// is the payout, and the first and second track IDs (e.g. `SM-B7L`) are the
+/// origin and destination tracks. Weights and lengths given only by a label,
+/// or in `tons` which could be either kind, are read in the preferred
+/// `units`. Payouts and quantities with a unit may have thousands separators
+/// (`$4,250`, `1,250 t`).
+pub fn parse_job_text(text: &str, units: Units) -> Vec<Order> {
+    let cleaned: String = strip_thousands_separators(text)
+        .replace("->", " ")
+        .chars()
+        .map(|c| if "→|,;()[]".contains(c) { ' ' } else { c })
+        .collect();
+    let tokens: Vec<&str> = cleaned
+        .split_whitespace()
+        .map(|t| t.trim_end_matches(':'))
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let mut jobs = Vec::new();
+    let mut current: Option<ParsedJob> = None;
+    for (ix, token) in tokens.iter().enumerate() {
+        if is_job_id(token) {
+            jobs.extend(current.take());
+            current = Some(ParsedJob {
+                job_id: (*token).to_owned(),
+                ..Default::default()
+            });
+            continue;
+        }
+        let Some(job) = current.as_mut() else {
+            continue;
+        };
+        if let Some(track) = Station::split_track_id(token) {
+            job.tracks.push(track);
+            continue;
+        }
+        if let Some(amount) = token.strip_prefix('$') {
+            job.payout = amount.parse().ok();
+            continue;
+        }
+        let Some((value, unit)) = split_number(token) else {
+            continue;
+        };
+        let next = tokens.get(ix + 1).copied().unwrap_or_default();
+        let unit = if unit.is_empty() { next } else { unit };
+        let label = ix
+            .checked_sub(1)
+            .and_then(|prev| tokens.get(prev))
+            .copied()
+            .unwrap_or_default();
+        match classify(unit, label, units) {
+            Some(Quantity::Cars) => job.car_count = Some(value.round() as u16),
+            Some(Quantity::Weight(unit)) => job.weight = Some(Mass::from_unit(value, unit)),
+            Some(Quantity::Length(unit)) => job.length = Some(Length::from_unit(value, unit)),
+            None => {}
+        }
+    }
+    jobs.extend(current);
+    jobs.into_iter().map(ParsedJob::into_order).collect()
+}
+
+/// Drops the commas between the digits of numbers like `4,250`, so that
+/// commas left over separate the fields of a job. Only payouts and numbers
+/// followed by a unit are read this way: in `5,312` the comma could as well
+/// separate two fields, so it is left alone.
+fn strip_thousands_separators(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut stripped = String::with_capacity(text.len());
+    let mut ix = 0;
+    while ix < chars.len() {
+        if let Some(end) = grouped_number_end(&chars, ix) {
+            stripped.extend(chars.iter().take(end).skip(ix).filter(|c| **c != ','));
+            ix = end;
+        } else {
+            stripped.extend(chars.get(ix));
+            ix += 1;
+        }
+    }
+    stripped
+}
+
+/// Finds the end of a number with thousands separators starting at `start`,
+/// if it is a payout or followed by a unit.
+fn grouped_number_end(chars: &[char], start: usize) -> Option<usize> {
+    let is_digit = |ix: usize| chars.get(ix).is_some_and(char::is_ascii_digit);
+    let before = start.checked_sub(1).and_then(|ix| chars.get(ix));
+    if before.is_some_and(|c| c.is_ascii_digit() || *c == '.' || *c == ',') {
+        return None;
+    }
+    let lead = (start..).take_while(|ix| is_digit(*ix)).count();
+    if !(1..=3).contains(&lead) {
+        return None;
+    }
+    let mut end = start + lead;
+    while chars.get(end) == Some(&',') && (end + 1..end + 4).all(is_digit) && !is_digit(end + 4) {
+        end += 4;
+    }
+    if end == start + lead {
+        return None;
+    }
+    // A decimal part and spaces may come between the number and its unit.
+    let unit = chars
+        .iter()
+        .skip(end)
+        .skip_while(|c| c.is_ascii_digit() || **c == '.')
+        .find(|c| **c != ' ');
+    (before == Some(&'$') || unit.is_some_and(|c| c.is_alphabetic())).then_some(end)
+}
+
+/// Job IDs look like `SM-FH-12`: origin station, job type and a number.
+fn is_job_id(token: &str) -> bool {
+    let parts: Vec<&str> = token.split('-').collect();
+    match parts.as_slice() {
+        [station, kind, number] => {
+            Station::from_abbrev(station).is_some()
+                && !kind.is_empty()
+                && kind.chars().all(|c| c.is_ascii_uppercase())
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        _ => false,
+    }
+}
+
+/// Splits a token like `312.5t` into its value and unit suffix.
+fn split_number(token: &str) -> Option<(f32, &str)> {
+    let end = token
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(token.len());
+    let (number, unit) = token.split_at(end);
+    number.parse().ok().map(|value| (value, unit))
+}
+
+fn classify(unit: &str, label: &str, units: Units) -> Option<Quantity> {
+    // A unit right next to the number wins over a label further away.
+    let by_unit = match unit.to_lowercase().as_str() {
+        "car" | "cars" => Some(Quantity::Cars),
+        "t" | "tonne" | "tonnes" => Some(Quantity::Weight(MassUnit::Tonnes)),
+        "tn" => Some(Quantity::Weight(MassUnit::ShortTons)),
+        "ton" | "tons" => Some(Quantity::Weight(units.mass)),
+        "m" | "meters" | "metres" => Some(Quantity::Length(LengthUnit::Meters)),
+        "ft" | "feet" => Some(Quantity::Length(LengthUnit::Feet)),
+        _ => None,
+    };
+    by_unit.or_else(|| match label.to_lowercase().as_str() {
+        "car" | "cars" => Some(Quantity::Cars),
+        "weight" | "mass" => Some(Quantity::Weight(units.mass)),
+        "length" => Some(Quantity::Length(units.length)),
+        _ => None,
+    })
+}
+
+/// Checks a parsed job the same way the order form checks a new order.
+fn row_errors(order: &Order, units: Units, i18n: &I18n) -> OrderFormErrors {
+    let mut form = OrderModal::new(OrderModalMode::New);
+    form.units = units;
+    form.init_from_order(order, 0);
+    form.validate(i18n)
+}
+
+fn station_combo(ui: &mut egui::Ui, id: egui::Id, station: &mut Station) {
+    egui::ComboBox::from_id_salt(id)
+        .width(60.0)
+        .selected_text(station.to_abbrev())
+        .show_ui(ui, |ui| {
+            for s in STATIONS {
+                ui.selectable_value(station, s, s.to_abbrev());
+            }
+        });
+}
+
+/// A dialog for adding several orders at once from pasted job text.
+#[derive(Default)]
+pub struct PasteJobsModal {
+    pub open: bool,
+    pub text: String,
+    pub preview: Vec<Order>,
+    pub orders: Option<Vec<Order>>,
+    /// Parsed jobs to put on the job board instead of taking them.
+    pub jobs: Option<Vec<Order>>,
+    /// The units the preview shows weights and lengths in.
+    pub units: Units,
+}
+
+impl PasteJobsModal {
+    pub fn show(&mut self, ctx: &egui::Context, i18n: &I18n) {
+        if !self.open {
+            return;
+        }
+        egui::Modal::new("paste_jobs_modal".into()).show(ctx, |ui| {
+            ui.set_width(700.0);
+            ui.heading(i18n.tr("paste-heading"));
+            ui.label(i18n.tr("paste-hint"));
+            egui::ScrollArea::vertical()
+                .id_salt("paste_jobs_text")
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.text)
+                            .desired_width(f32::INFINITY)
+                            .hint_text("SM-FH-12  5 cars  312.5 t  74.5 m  SM-B7L -> HB-C2I"),
+                    );
+                });
+            if ui.button(i18n.tr("paste-parse")).clicked() {
+                self.preview = parse_job_text(&self.text, self.units);
+            }
+            ui.separator();
+            self.show_preview(ui, i18n);
+            ui.separator();
+            // Checked after the preview, which may have just been edited.
+            let errors = self.errors(i18n);
+            let valid = errors.iter().filter(|e| e.is_valid()).count();
+            egui::Sides::new().show(
+                ui,
+                |_ui| {},
+                |ui| {
+                    let add_label = i18n.tr_args("paste-add", &[("count", valid.to_string())]);
+                    if ui
+                        .add_enabled(valid > 0, egui::Button::new(add_label))
+                        .clicked()
+                    {
+                        self.orders = Some(self.take_valid(&errors));
+                    }
+                    if ui
+                        .add_enabled(valid > 0, egui::Button::new(i18n.tr("paste-add-to-board")))
+                        .clicked()
+                    {
+                        self.jobs = Some(self.take_valid(&errors));
+                    }
+                    // Rows that can't be added yet stay behind to be fixed.
+                    if self.preview.is_empty() && (self.orders.is_some() || self.jobs.is_some()) {
+                        self.text.clear();
+                        self.open = false;
+                        ui.close();
+                    }
+                    if ui.button(i18n.tr("button-cancel")).clicked() {
+                        self.open = false;
+                        ui.close();
+                    }
+                },
+            );
+        });
+    }
+
+    // Takes the valid rows out of the preview, leaving the invalid ones.
+    fn take_valid(&mut self, errors: &[OrderFormErrors]) -> Vec<Order> {
+        let (valid, invalid) = std::mem::take(&mut self.preview)
+            .into_iter()
+            .zip(errors)
+            .partition::<Vec<_>, _>(|(_, errors)| errors.is_valid());
+        self.preview = invalid.into_iter().map(|(order, _)| order).collect();
+        valid.into_iter().map(|(order, _)| order).collect()
+    }
+
+    fn errors(&self, i18n: &I18n) -> Vec<OrderFormErrors> {
+        self.preview
+            .iter()
+            .map(|order| row_errors(order, self.units, i18n))
+            .collect()
+    }
+
+    fn show_preview(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        if self.preview.is_empty() {
+            ui.label(i18n.tr("paste-empty"));
+            return;
+        }
+        let errors = self.errors(i18n);
+        let mut to_remove = None;
+        let units = self.units;
+        egui::ScrollArea::vertical()
+            .id_salt("paste_jobs_preview")
+            .max_height(250.0)
+            .show(ui, |ui| {
+                egui::Grid::new("paste_jobs_grid")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for key in [
+                            "order-form-job-id",
+                            "order-form-cars",
+                            "column-weight",
+                            "column-length",
+                            "paste-pickup",
+                            "paste-track",
+                            "paste-dropoff",
+                            "paste-track",
+                        ] {
+                            ui.label(egui::RichText::new(i18n.tr(key)).strong());
+                        }
+                        ui.label("");
+                        ui.label("");
+                        ui.end_row();
+                        for (ix, order) in self.preview.iter_mut().enumerate() {
+                            let id = egui::Id::new("paste_jobs_row").with(ix);
+                            if ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut order.job_id)
+                                        .desired_width(80.0),
+                                )
+                                .changed()
+                            {
+                                order.name = order.job_id.clone();
+                            }
+                            ui.add(egui::DragValue::new(&mut order.car_count));
+                            let mut weight = order.weight.in_unit(units.mass);
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut weight)
+                                        .suffix(format!(" {}", units.mass.symbol())),
+                                )
+                                .changed()
+                            {
+                                order.weight = Mass::from_unit(weight, units.mass);
+                            }
+                            let mut length = order.length.in_unit(units.length);
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut length)
+                                        .suffix(format!(" {}", units.length.symbol())),
+                                )
+                                .changed()
+                            {
+                                order.length = Length::from_unit(length, units.length);
+                            }
+                            station_combo(ui, id.with("pickup"), &mut order.pickup_station);
+                            ui.add(
+                                egui::TextEdit::singleline(&mut order.pickup_track)
+                                    .desired_width(50.0),
+                            );
+                            station_combo(ui, id.with("dropoff"), &mut order.dropoff_station);
+                            ui.add(
+                                egui::TextEdit::singleline(&mut order.dropoff_track)
+                                    .desired_width(50.0),
+                            );
+                            if ui.button(i18n.tr("paste-remove")).clicked() {
+                                to_remove = Some(ix);
+                            }
+                            match errors.get(ix).and_then(OrderFormErrors::first) {
+                                Some(error) => {
+                                    ui.colored_label(ui.visuals().error_fg_color, error);
+                                }
+                                None => {
+                                    ui.label("");
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        if let Some(ix) = to_remove {
+            self.preview.remove(ix);
+        }
+    }
+}
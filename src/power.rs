@@ -0,0 +1,279 @@
+//! Suggesting the locomotives to pull a set of orders.
+//!
+//! The search tries every combination of up to [`MAX_SUGGESTED_UNITS`]
+//! locomotives the player can use, all at the head end, and keeps those that
+//! can pull the train up every leg of the planned route in the chosen
+//! weather. Units without an engine, like cabooses and slugs, stay on the
+//! train and count toward what has to be pulled.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::locomotive::{LOCO_LIST, Locomotive, LocomotiveInfo, locomotives};
+use crate::route::legs;
+use crate::units::Mass;
+
+/// The most locomotives a suggestion may have.
+pub const MAX_SUGGESTED_UNITS: usize = 4;
+
+/// How many suggestions are shown.
+pub const SUGGESTIONS: usize = 3;
+
+/// What makes one set of locomotives better than another.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum PowerGoal {
+    #[default]
+    Fewest,
+    Lightest,
+    Cheapest,
+}
+
+impl Display for PowerGoal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let goal_str = match self {
+            Self::Fewest => "Fewest units",
+            Self::Lightest => "Lightest",
+            Self::Cheapest => "Cheapest to run (estimate)",
+        };
+        write!(f, "{goal_str}")
+    }
+}
+
+pub const POWER_GOALS: [PowerGoal; 3] =
+    [PowerGoal::Fewest, PowerGoal::Lightest, PowerGoal::Cheapest];
+
+/// The locomotives the player may and can use, as far as they have told the
+/// app. Anything not mentioned is assumed available.
+#[derive(Clone, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Roster {
+    /// Locomotives the player has no license for.
+    pub unlicensed: BTreeSet<Locomotive>,
+    /// How many of each locomotive the player has, where known.
+    pub owned: BTreeMap<Locomotive, u8>,
+}
+
+impl Roster {
+    pub fn is_licensed(&self, loco: Locomotive) -> bool {
+        !self.unlicensed.contains(&loco)
+    }
+
+    pub fn set_licensed(&mut self, loco: Locomotive, licensed: bool) {
+        if licensed {
+            self.unlicensed.remove(&loco);
+        } else {
+            self.unlicensed.insert(loco);
+        }
+    }
+
+    /// How many of the locomotive the player has, or `None` if unknown.
+    pub fn owned(&self, loco: Locomotive) -> Option<u8> {
+        self.owned.get(&loco).copied()
+    }
+
+    pub fn set_owned(&mut self, loco: Locomotive, owned: Option<u8>) {
+        match owned {
+            Some(count) => self.owned.insert(loco, count),
+            None => self.owned.remove(&loco),
+        };
+    }
+
+    /// The most of the locomotive a suggestion may use.
+    fn available(&self, loco: Locomotive) -> usize {
+        if !self.is_licensed(loco) {
+            return 0;
+        }
+        self.owned(loco).map_or(MAX_SUGGESTED_UNITS, |owned| {
+            usize::from(owned).min(MAX_SUGGESTED_UNITS)
+        })
+    }
+}
+
+/// A load the suggested locomotives have to pull up a grade.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Demand {
+    /// The cars and the units without an engine.
+    pub load: Mass,
+    /// The ruling grade, in percent.
+    pub grade: f32,
+}
+
+/// What the suggested locomotives have to pull: the train as it is now on
+/// the grade in the conditions, then each leg of the planned route at that
+/// leg's ruling grade.
+///
+/// The consist's locomotives with an engine are left out, since the
+/// suggestion replaces them. Those without one stay and are counted.
+pub fn demands(consist: &Consist, conditions: &Conditions) -> Vec<Demand> {
+    let all_locos: Mass = consist.locomotives.iter().map(|l| l.weight).sum();
+    let kept: Mass = consist
+        .locomotives
+        .iter()
+        .filter(|l| !l.has_power)
+        .map(|l| l.weight)
+        .sum();
+    let cars: Mass = consist.orders_on_train().map(|o| o.weight).sum();
+    let now = Demand {
+        load: cars + kept,
+        grade: conditions.grade,
+    };
+    let legs = legs(consist, conditions).into_iter().map(|leg| Demand {
+        load: leg.weight - all_locos + kept,
+        grade: leg.ruling_grade,
+    });
+    std::iter::once(now).chain(legs).collect()
+}
+
+/// A set of locomotives that can pull the load.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PowerOption {
+    /// Each kind of locomotive with how many of it.
+    pub locomotives: Vec<(Locomotive, usize)>,
+    /// The weight of the train with these locomotives where the margin is
+    /// smallest.
+    pub weight: Mass,
+    /// What the locomotives can pull there.
+    pub supported: Mass,
+    /// The weight of the locomotives alone.
+    pub loco_weight: Mass,
+    /// An estimate of what the locomotives cost to run, see
+    /// [`Locomotive::running_cost`].
+    pub running_cost: u32,
+}
+
+impl PowerOption {
+    pub fn units(&self) -> usize {
+        self.locomotives.iter().map(|(_, count)| count).sum()
+    }
+
+    /// How much more the locomotives could pull.
+    pub fn margin(&self) -> Mass {
+        self.supported - self.weight
+    }
+
+    /// The locomotives, in the order they are coupled.
+    pub fn infos(&self) -> Vec<LocomotiveInfo> {
+        self.locomotives
+            .iter()
+            .filter_map(|(loco, count)| Some((locomotives().get(loco)?, *count)))
+            .flat_map(|(info, count)| std::iter::repeat_n(info.clone(), count))
+            .collect()
+    }
+
+    fn compare(&self, other: &Self, goal: PowerGoal) -> Ordering {
+        let by_goal = match goal {
+            PowerGoal::Fewest => self.units().cmp(&other.units()),
+            PowerGoal::Lightest => self.loco_weight.total_cmp(&other.loco_weight),
+            PowerGoal::Cheapest => self.running_cost.cmp(&other.running_cost),
+        };
+        // Among equals, fewer units and then a bigger margin win.
+        by_goal
+            .then(self.units().cmp(&other.units()))
+            .then(other.margin().total_cmp(&self.margin()))
+    }
+}
+
+/// The best sets of locomotives for meeting every demand in the given
+/// weather, best first.
+pub fn suggest_power(
+    demands: &[Demand],
+    conditions: &Conditions,
+    roster: &Roster,
+    goal: PowerGoal,
+) -> Vec<PowerOption> {
+    let candidates: Vec<&LocomotiveInfo> = LOCO_LIST
+        .iter()
+        .filter_map(|loco| locomotives().get(loco))
+        .filter(|info| conditions.capacity(info) > 0.0 && roster.available(info.loco) > 0)
+        .collect();
+    let mut options = Vec::new();
+    let mut counts = vec![0; candidates.len()];
+    combinations(&candidates, roster, 0, &mut counts, &mut |counts| {
+        if let Some(option) = evaluate(&candidates, counts, demands, conditions) {
+            options.push(option);
+        }
+    });
+    options.sort_by(|a, b| a.compare(b, goal));
+    options.truncate(SUGGESTIONS);
+    options
+}
+
+// Calls `found` with every count of each candidate that adds up to between
+// one and MAX_SUGGESTED_UNITS locomotives.
+fn combinations(
+    candidates: &[&LocomotiveInfo],
+    roster: &Roster,
+    next: usize,
+    counts: &mut Vec<usize>,
+    found: &mut impl FnMut(&[usize]),
+) {
+    let used: usize = counts.iter().sum();
+    let Some(info) = candidates.get(next) else {
+        if used > 0 {
+            found(counts);
+        }
+        return;
+    };
+    let most = roster.available(info.loco).min(MAX_SUGGESTED_UNITS - used);
+    for count in 0..=most {
+        if let Some(slot) = counts.get_mut(next) {
+            *slot = count;
+        }
+        combinations(candidates, roster, next + 1, counts, found);
+    }
+    if let Some(slot) = counts.get_mut(next) {
+        *slot = 0;
+    }
+}
+
+fn evaluate(
+    candidates: &[&LocomotiveInfo],
+    counts: &[usize],
+    demands: &[Demand],
+    conditions: &Conditions,
+) -> Option<PowerOption> {
+    let chosen: Vec<(&LocomotiveInfo, usize)> = candidates
+        .iter()
+        .zip(counts)
+        .filter(|(_, count)| **count > 0)
+        .map(|(info, count)| (*info, *count))
+        .collect();
+    let locos = chosen.iter().fold(Mass::ZERO, |a, (info, count)| {
+        a + info.weight * *count as f32
+    });
+    // The demand the locomotives come closest to failing.
+    let (weight, supported) = demands
+        .iter()
+        .map(|demand| {
+            let grade_conditions = Conditions {
+                grade: demand.grade,
+                ..*conditions
+            };
+            // All the power is on the head end, so one coupler takes the
+            // whole load if couplers are limited.
+            let pull: f32 = chosen
+                .iter()
+                .map(|(info, count)| grade_conditions.capacity(info) * *count as f32)
+                .sum();
+            let limit = conditions.coupler_limit.map_or(f32::INFINITY, Mass::tonnes);
+            let supported = Mass::from_tonnes(pull.min(limit));
+            (demand.load + locos, supported)
+        })
+        .min_by(|a, b| (a.1 - a.0).total_cmp(&(b.1 - b.0)))?;
+    (weight <= supported).then(|| PowerOption {
+        locomotives: chosen
+            .iter()
+            .map(|(info, count)| (info.loco, *count))
+            .collect(),
+        weight,
+        supported,
+        loco_weight: locos,
+        running_cost: chosen
+            .iter()
+            .map(|(info, count)| u32::from(info.loco.running_cost()) * *count as u32)
+            .sum(),
+    })
+}
@@ -0,0 +1,170 @@
+//! A window suggesting locomotives that can pull the orders, and where the
+//! player tells the app which locomotives they can use.
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::history::Command;
+use crate::i18n::I18n;
+use crate::locomotive::{LOCO_LIST, locomotives};
+use crate::power::{
+    MAX_SUGGESTED_UNITS, POWER_GOALS, PowerGoal, PowerOption, Roster, demands, suggest_power,
+};
+use crate::units::{Mass, Units};
+
+/// The most of one locomotive the roster lets the player say they own.
+const MAX_OWNED: u8 = 20;
+
+#[derive(Default)]
+pub struct PowerWindow {
+    pub open: bool,
+    goal: PowerGoal,
+    /// The command replacing the consist's power with a chosen suggestion.
+    pub command: Option<Command>,
+}
+
+impl PowerWindow {
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        consist: &Consist,
+        conditions: &Conditions,
+        roster: &mut Roster,
+        i18n: &I18n,
+        units: &Units,
+    ) {
+        let mut open = self.open;
+        egui::Window::new(i18n.tr("power-heading"))
+            .id(egui::Id::new("power_window"))
+            .open(&mut open)
+            .default_width(380.0)
+            .show(ctx, |ui| {
+                let demands = demands(consist, conditions);
+                let load = demands
+                    .iter()
+                    .map(|d| d.load)
+                    .fold(Mass::ZERO, |a, b| if b > a { b } else { a });
+                ui.label(i18n.tr_args("power-load", &[("weight", units.mass(load))]));
+                egui::ComboBox::from_label(i18n.tr("power-goal"))
+                    .selected_text(goal_label(i18n, self.goal))
+                    .show_ui(ui, |ui| {
+                        for goal in POWER_GOALS {
+                            ui.selectable_value(&mut self.goal, goal, goal_label(i18n, goal));
+                        }
+                    });
+                ui.separator();
+                let options = suggest_power(&demands, conditions, roster, self.goal);
+                if options.is_empty() {
+                    ui.label(
+                        i18n.tr_args("power-none", &[("units", MAX_SUGGESTED_UNITS.to_string())]),
+                    );
+                }
+                egui::Grid::new("power_options")
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for option in &options {
+                            ui.label(describe(option)).on_hover_text(i18n.tr_args(
+                                "power-details",
+                                &[
+                                    ("weight", units.mass(option.weight)),
+                                    ("supported", units.mass(option.supported)),
+                                    ("cost", option.running_cost.to_string()),
+                                ],
+                            ));
+                            ui.label(i18n.tr_args(
+                                "power-margin",
+                                &[("weight", units.mass(option.margin()))],
+                            ));
+                            if ui.button(i18n.tr("power-apply")).clicked() {
+                                self.command = replace_power(consist, option);
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+                egui::CollapsingHeader::new(i18n.tr("power-roster"))
+                    .id_salt("power_roster")
+                    .show(ui, |ui| roster_ui(ui, roster, i18n));
+            });
+        self.open = open;
+    }
+}
+
+fn goal_label(i18n: &I18n, goal: PowerGoal) -> String {
+    i18n.tr(match goal {
+        PowerGoal::Fewest => "power-goal-fewest",
+        PowerGoal::Lightest => "power-goal-lightest",
+        PowerGoal::Cheapest => "power-goal-cheapest",
+    })
+}
+
+// E.g. `2 × DE6 + DH4`.
+fn describe(option: &PowerOption) -> String {
+    let parts: Vec<String> = option
+        .locomotives
+        .iter()
+        .map(|(loco, count)| match count {
+            1 => loco.to_string(),
+            _ => format!("{count} × {loco}"),
+        })
+        .collect();
+    parts.join(" + ")
+}
+
+// Takes off every locomotive with an engine and puts the suggested ones on
+// the head end, as one undoable step. Cabooses and slugs stay, and the
+// suggestion counted their weight.
+fn replace_power(consist: &Consist, option: &PowerOption) -> Option<Command> {
+    let removals = consist
+        .locomotives
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, l)| l.has_power)
+        .map(|(index, loco)| Command::DeleteLocomotive {
+            index,
+            loco: loco.clone(),
+        });
+    let additions = option
+        .infos()
+        .into_iter()
+        .enumerate()
+        .map(|(index, loco)| Command::AddLocomotive { index, loco });
+    let commands: Vec<Command> = removals.chain(additions).collect();
+    (!commands.is_empty()).then_some(Command::Batch(commands))
+}
+
+// Which locomotives the player has a license for and how many they own.
+fn roster_ui(ui: &mut egui::Ui, roster: &mut Roster, i18n: &I18n) {
+    egui::Grid::new("power_roster_grid")
+        .num_columns(3)
+        .show(ui, |ui| {
+            for loco in LOCO_LIST {
+                if !locomotives().get(&loco).is_some_and(|l| l.has_power) {
+                    continue;
+                }
+                ui.label(loco.to_string());
+                let mut licensed = roster.is_licensed(loco);
+                if ui
+                    .checkbox(&mut licensed, i18n.tr("power-licensed"))
+                    .changed()
+                {
+                    roster.set_licensed(loco, licensed);
+                }
+                ui.horizontal(|ui| {
+                    let mut known = roster.owned(loco).is_some();
+                    if ui.checkbox(&mut known, i18n.tr("power-owned")).changed() {
+                        roster.set_owned(loco, known.then_some(1));
+                    }
+                    if let Some(mut owned) = roster.owned(loco)
+                        && ui
+                            .add(egui::DragValue::new(&mut owned).range(0..=MAX_OWNED))
+                            .changed()
+                    {
+                        roster.set_owned(loco, Some(owned));
+                    }
+                });
+                ui.end_row();
+            }
+        });
+}
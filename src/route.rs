@@ -0,0 +1,141 @@
+//! Working out which stations a consist has to visit, and in what order.
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::grades::ruling_grade;
+use crate::order::{Order, OrderStatus};
+use crate::station::Station;
+use crate::units::{Length, Mass};
+
+/// The stops a consist makes to work its orders: the pickup station of every
+/// planned order in the order the orders are listed, then the dropoff station
+/// of every order not yet delivered the same way.
+///
+/// Each station appears once among the pickups and once among the dropoffs,
+/// except that the first dropoff is skipped when the train is already there.
+pub fn planned_route(orders: &[Order]) -> Vec<Station> {
+    let mut route: Vec<Station> = Vec::new();
+    for order in orders.iter().filter(|o| o.status == OrderStatus::Planned) {
+        if !route.contains(&order.pickup_station) {
+            route.push(order.pickup_station);
+        }
+    }
+    let pickups = route.len();
+    for order in orders.iter().filter(|o| o.status != OrderStatus::Delivered) {
+        let station = order.dropoff_station;
+        let already_visited = route.get(pickups..).is_some_and(|d| d.contains(&station));
+        if !already_visited && route.last() != Some(&station) {
+            route.push(station);
+        }
+    }
+    route
+}
+
+/// The orders to set out at a station: those on the train headed there.
+pub fn set_outs(orders: &[Order], station: Station) -> Vec<usize> {
+    orders
+        .iter()
+        .enumerate()
+        .filter(|(_, o)| o.status == OrderStatus::PickedUp && o.dropoff_station == station)
+        .map(|(ix, _)| ix)
+        .collect()
+}
+
+/// The orders to pick up at a station: those still waiting there.
+pub fn pick_ups(orders: &[Order], station: Station) -> Vec<usize> {
+    orders
+        .iter()
+        .enumerate()
+        .filter(|(_, o)| o.status == OrderStatus::Planned && o.pickup_station == station)
+        .map(|(ix, _)| ix)
+        .collect()
+}
+
+/// The consist as it will be after working a station.
+pub fn after_stop(consist: &Consist, station: Station) -> Consist {
+    let mut next = consist.clone();
+    for ix in set_outs(&consist.orders, station) {
+        if let Some(order) = next.orders.get_mut(ix) {
+            order.status = OrderStatus::Delivered;
+        }
+    }
+    for ix in pick_ups(&consist.orders, station) {
+        if let Some(order) = next.orders.get_mut(ix) {
+            order.status = OrderStatus::PickedUp;
+        }
+    }
+    next
+}
+
+// The most `measure` gives for the train while working the planned route,
+// starting with the train as it is now.
+fn peak<T: PartialOrd>(consist: &Consist, measure: impl Fn(&Consist) -> T) -> T {
+    let mut train = consist.clone();
+    let mut most = measure(&train);
+    for station in planned_route(&consist.orders) {
+        train = after_stop(&train, station);
+        let value = measure(&train);
+        if value > most {
+            most = value;
+        }
+    }
+    most
+}
+
+/// The longest the cars on the train get while working the planned route,
+/// starting with the cars on it now.
+pub fn longest_load(consist: &Consist) -> Length {
+    peak(consist, |c| {
+        c.orders_on_train().map(|o| o.length).sum::<Length>()
+    })
+}
+
+/// One station-to-station leg of the planned route, with the train as it
+/// runs that leg.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Leg {
+    pub from: Station,
+    pub to: Station,
+    /// The steepest climb on the leg, in percent.
+    pub ruling_grade: f32,
+    pub weight: Mass,
+    /// What the locomotives can pull up the ruling grade.
+    pub supported: Mass,
+}
+
+impl Leg {
+    /// Whether the train is too heavy to get up the leg's ruling grade.
+    pub fn stalls(&self) -> bool {
+        self.weight > self.supported
+    }
+}
+
+/// The legs between the stops of the planned route, each checked against
+/// its ruling grade in the given weather. The train on each leg is the
+/// consist after working every stop before it.
+pub fn legs(consist: &Consist, conditions: &Conditions) -> Vec<Leg> {
+    let route = planned_route(&consist.orders);
+    let mut train = consist.clone();
+    let mut legs = Vec::new();
+    for pair in route.windows(2) {
+        let [from, to] = *pair else {
+            continue;
+        };
+        train = after_stop(&train, from);
+        let Some(grade) = ruling_grade(from, to) else {
+            continue;
+        };
+        let leg_conditions = Conditions {
+            grade,
+            ..*conditions
+        };
+        legs.push(Leg {
+            from,
+            to,
+            ruling_grade: grade,
+            weight: train.summary(conditions.coupler_limit).total_weight,
+            supported: train.supported_weight(&leg_conditions),
+        });
+    }
+    legs
+}
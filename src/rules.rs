@@ -0,0 +1,199 @@
+//! Lint-style checks of how a consist is made up.
+//!
+//! Each rule looks at the locomotives and orders of a consist for something
+//! that is allowed by the game but likely a mistake. Rules can be turned off
+//! and given their own severity, and the settings are saved with the app.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use crate::consist::{Consist, TrainUnit};
+use crate::hazmat::{next_to_locomotive, unseparated};
+use crate::locomotive::Locomotive;
+use crate::order::OrderStatus;
+use crate::units::Mass;
+
+/// How much a broken rule matters.
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, serde::Deserialize, serde::Serialize,
+)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let severity_str = match self {
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+        };
+        write!(f, "{severity_str}")
+    }
+}
+
+pub const SEVERITIES: [Severity; 3] = [Severity::Info, Severity::Warning, Severity::Error];
+
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, serde::Deserialize, serde::Serialize,
+)]
+pub enum Rule {
+    /// A caboose on the train is the last unit.
+    CabooseLast,
+    /// No cut with hazardous cargo is coupled next to a locomotive or the
+    /// caboose.
+    #[serde(alias = "HazmatBehindLocomotive")]
+    HazmatNextToLocomotive,
+    /// Hazmat cuts of different classes have other cars between them.
+    HazmatSeparation,
+    /// A DE6 Slug, which has no engine of its own, is coupled next to a DE6.
+    SlugNextToDe6,
+    /// No order still to be worked weighs nothing.
+    ZeroWeightOrder,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rule_str = match self {
+            Self::CabooseLast => "Caboose must be last",
+            Self::HazmatNextToLocomotive => "No hazmat cut next to a locomotive or the caboose",
+            Self::HazmatSeparation => "Buffer cars between different hazmat classes",
+            Self::SlugNextToDe6 => "A DE6 Slug must be next to a DE6",
+            Self::ZeroWeightOrder => "No order without weight",
+        };
+        write!(f, "{rule_str}")
+    }
+}
+
+impl Rule {
+    pub fn default_severity(self) -> Severity {
+        match self {
+            Self::CabooseLast | Self::ZeroWeightOrder => Severity::Warning,
+            Self::HazmatNextToLocomotive | Self::HazmatSeparation | Self::SlugNextToDe6 => {
+                Severity::Error
+            }
+        }
+    }
+}
+
+pub const RULES: [Rule; 5] = [
+    Rule::CabooseLast,
+    Rule::HazmatNextToLocomotive,
+    Rule::HazmatSeparation,
+    Rule::SlugNextToDe6,
+    Rule::ZeroWeightOrder,
+];
+
+/// Whether a rule is checked, and how much breaking it matters.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RuleSetting {
+    pub enabled: bool,
+    pub severity: Severity,
+}
+
+/// The settings of every rule. Rules the user hasn't changed are enabled
+/// with their default severity.
+#[derive(Clone, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RuleConfig {
+    settings: BTreeMap<Rule, RuleSetting>,
+}
+
+impl RuleConfig {
+    pub fn get(&self, rule: Rule) -> RuleSetting {
+        self.settings.get(&rule).copied().unwrap_or(RuleSetting {
+            enabled: true,
+            severity: rule.default_severity(),
+        })
+    }
+
+    pub fn set(&mut self, rule: Rule, setting: RuleSetting) {
+        self.settings.insert(rule, setting);
+    }
+}
+
+/// A rule the consist breaks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Violation {
+    pub rule: Rule,
+    pub severity: Severity,
+    /// The orders that break the rule, as indices into the consist's
+    /// `orders`, for rules about orders.
+    pub orders: Vec<usize>,
+}
+
+/// Checks the consist against every enabled rule, most severe first.
+pub fn check(consist: &Consist, config: &RuleConfig) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for rule in RULES {
+        let setting = config.get(rule);
+        if !setting.enabled {
+            continue;
+        }
+        violations.extend(
+            broken_by(consist, rule)
+                .into_iter()
+                .map(|orders| Violation {
+                    rule,
+                    severity: setting.severity,
+                    orders,
+                }),
+        );
+    }
+    violations.sort_by_key(|v| Reverse(v.severity));
+    violations
+}
+
+fn is_loco(unit: Option<&TrainUnit<'_>>, loco: Locomotive) -> bool {
+    matches!(unit, Some(TrainUnit::Locomotive(l)) if l.loco == loco)
+}
+
+// Each time the consist breaks the rule, with the offending orders for
+// rules about orders.
+fn broken_by(consist: &Consist, rule: Rule) -> Vec<Vec<usize>> {
+    let units = consist.units();
+    match rule {
+        Rule::CabooseLast => {
+            let has_caboose = consist
+                .locomotives
+                .iter()
+                .any(|l| l.loco == Locomotive::Caboose);
+            if has_caboose && !is_loco(units.last(), Locomotive::Caboose) {
+                vec![Vec::new()]
+            } else {
+                Vec::new()
+            }
+        }
+        Rule::HazmatNextToLocomotive => next_to_locomotive(consist)
+            .into_iter()
+            .map(|ix| vec![ix])
+            .collect(),
+        Rule::HazmatSeparation => unseparated(consist)
+            .into_iter()
+            .map(|(first, second)| vec![first, second])
+            .collect(),
+        Rule::SlugNextToDe6 => units
+            .iter()
+            .enumerate()
+            .filter(|(ix, unit)| {
+                is_loco(Some(unit), Locomotive::DE6Slug)
+                    && !is_loco(
+                        ix.checked_sub(1).and_then(|ix| units.get(ix)),
+                        Locomotive::DE6,
+                    )
+                    && !is_loco(units.get(ix + 1), Locomotive::DE6)
+            })
+            .map(|_| Vec::new())
+            .collect(),
+        Rule::ZeroWeightOrder => consist
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.status != OrderStatus::Delivered && o.weight <= Mass::ZERO)
+            .map(|(ix, _)| vec![ix])
+            .collect(),
+    }
+}
@@ -0,0 +1,215 @@
+//! Reads the jobs in the player's inventory out of a Derail Valley savegame.
+//!
+//! Only the plain JSON save layout is understood. Encrypted or binary saves,
+//! and saves written with a save version we haven't seen, are rejected with a
+//! [`SaveError`] instead of being guessed at.
+//!
+//! The layout is not published by the game. What is read here is a small
+//! subset of the save: `saveVersion`, the `jobs` with their `state`, tracks
+//! and car IDs, and the `cars` with their type and cargo. Track IDs follow
+//! the station yard signs (`SM-B7L`) and car types use the game's internal
+//! names, see [`CarType::from_save_name`]. The version numbers and the
+//! `"InProgress"` state for taken jobs are assumptions made to match that
+//! layout, not values checked against the game's code, so a save that
+//! doesn't parse should be reported with the file attached. The fixtures in
+//! `tests/fixtures` are written by hand in this layout, as described in the
+//! README there.
+//!
+//! Until the layout has been checked against a real save, the app doesn't
+//! offer to import saves; this module is only reachable from code.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use crate::car::CarType;
+use crate::hazmat::HazmatClass;
+use crate::order::{Order, OrderStatus};
+use crate::station::Station;
+use crate::units::{Length, Mass};
+
+/// Save versions whose layout matches what this module parses. These are
+/// the versions the hand-written fixtures use; see the module docs.
+const SUPPORTED_SAVE_VERSIONS: [u32; 2] = [2, 3];
+
+/// The job state taken to mean a job is in the player's inventory.
+const TAKEN_JOB_STATE: &str = "InProgress";
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    NotJson,
+    Malformed(serde_json::Error),
+    UnsupportedVersion(u32),
+    UnknownCar { job_id: String, car_id: String },
+    UnknownCarType { car_id: String, car_type: String },
+    UnknownTrack { job_id: String, track: String },
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Could not read the save file: {err}"),
+            Self::NotJson => write!(
+                f,
+                "The save file is not plain JSON. Encrypted and binary saves are not supported."
+            ),
+            Self::Malformed(err) => {
+                write!(f, "The save file is not a savegame we understand: {err}")
+            }
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Save version {version} is not supported (supported versions: {SUPPORTED_SAVE_VERSIONS:?})"
+            ),
+            Self::UnknownCar { job_id, car_id } => {
+                write!(
+                    f,
+                    "Job {job_id} refers to car {car_id}, which is not in the save"
+                )
+            }
+            Self::UnknownCarType { car_id, car_type } => {
+                write!(f, "Car {car_id} has an unknown car type \"{car_type}\"")
+            }
+            Self::UnknownTrack { job_id, track } => {
+                write!(f, "Job {job_id} uses an unknown track \"{track}\"")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Malformed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveGame {
+    save_version: u32,
+    #[serde(default)]
+    jobs: Vec<SaveJob>,
+    #[serde(default)]
+    cars: Vec<SaveCar>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveJob {
+    id: String,
+    state: String,
+    origin_track: String,
+    destination_track: String,
+    cars: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveCar {
+    id: String,
+    #[serde(rename = "type")]
+    car_type: String,
+    #[serde(default)]
+    cargo: Option<String>,
+    #[serde(default)]
+    cargo_amount: f32,
+}
+
+/// Parses the contents of a savegame and returns the player's taken jobs as orders.
+///
+/// # Errors
+///
+/// Returns an error if the save isn't plain JSON, has an unsupported save
+/// version, or refers to cars or tracks that can't be resolved.
+pub fn parse_save(data: &[u8]) -> Result<Vec<Order>, SaveError> {
+    let first_byte = data.iter().find(|b| !b.is_ascii_whitespace());
+    if first_byte != Some(&b'{') {
+        return Err(SaveError::NotJson);
+    }
+    let save: SaveGame = serde_json::from_slice(data).map_err(SaveError::Malformed)?;
+    if !SUPPORTED_SAVE_VERSIONS.contains(&save.save_version) {
+        return Err(SaveError::UnsupportedVersion(save.save_version));
+    }
+
+    let cars: HashMap<&str, &SaveCar> = save.cars.iter().map(|c| (c.id.as_str(), c)).collect();
+    save.jobs
+        .iter()
+        .filter(|job| job.state == TAKEN_JOB_STATE)
+        .map(|job| job_to_order(job, &cars))
+        .collect()
+}
+
+/// Reads a savegame from disk and returns the player's taken jobs as orders.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or [`parse_save`] fails.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_save(path: &std::path::Path) -> Result<Vec<Order>, SaveError> {
+    let data = std::fs::read(path).map_err(SaveError::Io)?;
+    parse_save(&data)
+}
+
+fn job_to_order(job: &SaveJob, cars: &HashMap<&str, &SaveCar>) -> Result<Order, SaveError> {
+    let split_track = |track: &str| {
+        Station::split_track_id(track).ok_or_else(|| SaveError::UnknownTrack {
+            job_id: job.id.clone(),
+            track: track.to_owned(),
+        })
+    };
+    let (pickup_station, pickup_track) = split_track(&job.origin_track)?;
+    let (dropoff_station, dropoff_track) = split_track(&job.destination_track)?;
+
+    let mut weight = Mass::ZERO;
+    let mut length = Length::ZERO;
+    let mut car_types = Vec::new();
+    let mut cargo: Vec<&str> = Vec::new();
+    for car_id in &job.cars {
+        let car = cars
+            .get(car_id.as_str())
+            .ok_or_else(|| SaveError::UnknownCar {
+                job_id: job.id.clone(),
+                car_id: car_id.clone(),
+            })?;
+        let car_type =
+            CarType::from_save_name(&car.car_type).ok_or_else(|| SaveError::UnknownCarType {
+                car_id: car.id.clone(),
+                car_type: car.car_type.clone(),
+            })?;
+        let info = car_type.info();
+        weight += info.empty_weight + info.cargo_capacity * car.cargo_amount.clamp(0.0, 1.0);
+        length += info.length;
+        car_types.push(car_type);
+        if let Some(car_cargo) = &car.cargo
+            && !cargo.contains(&car_cargo.as_str())
+        {
+            cargo.push(car_cargo);
+        }
+    }
+
+    let car_type = car_types
+        .first()
+        .copied()
+        .filter(|first| car_types.iter().all(|c| c == first));
+    let cargo = cargo.join(", ");
+    Ok(Order {
+        name: job.id.clone(),
+        job_id: job.id.clone(),
+        car_count: u16::try_from(job.cars.len()).unwrap_or(u16::MAX),
+        car_type,
+        hazmat: HazmatClass::from_cargo(&cargo),
+        cargo,
+        weight,
+        length,
+        pickup_station,
+        pickup_track,
+        dropoff_station,
+        dropoff_track,
+        status: OrderStatus::Planned,
+        speed_limit_kmh: None,
+        payout: None,
+    })
+}
@@ -0,0 +1,54 @@
+//! How fast a train may run, and which of its locomotives, cars or cargo
+//! holds it back.
+//!
+//! Speeds are in km/h, the unit the game's speedometers and signs use.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::car::CarType;
+use crate::locomotive::Locomotive;
+
+/// The limit for cars carrying hazardous cargo, whatever the car type.
+pub const HAZMAT_SPEED_KMH: u16 = 60;
+
+/// Below this, a locomotive capping the train is worth a warning.
+pub const SLOW_SPEED_KMH: u16 = 60;
+
+/// What sets the top speed of a train.
+#[derive(Clone, PartialEq, Eq, Debug, serde::Serialize)]
+pub enum SpeedLimiter {
+    Locomotive {
+        loco: Locomotive,
+        reversed: bool,
+    },
+    CarType(CarType),
+    /// Hazardous cargo in the named order.
+    Hazmat(String),
+    /// The limit given on the named order itself.
+    Order(String),
+}
+
+impl Display for SpeedLimiter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Locomotive {
+                loco,
+                reversed: false,
+            } => write!(f, "{loco}"),
+            Self::Locomotive {
+                loco,
+                reversed: true,
+            } => write!(f, "{loco} in reverse"),
+            Self::CarType(car_type) => write!(f, "{car_type} cars"),
+            Self::Hazmat(order) => write!(f, "hazmat in {order}"),
+            Self::Order(order) => write!(f, "limit on {order}"),
+        }
+    }
+}
+
+/// A speed limit and what sets it.
+#[derive(Clone, PartialEq, Eq, Debug, serde::Serialize)]
+pub struct SpeedLimit {
+    pub kmh: u16,
+    pub limiter: SpeedLimiter,
+}
@@ -1,6 +1,8 @@
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, serde::Deserialize, serde::Serialize,
+)]
 pub enum Station {
     CitySouth,
     CityWest,
@@ -78,6 +80,75 @@ impl Station {
         }
         .to_owned()
     }
+
+    pub fn from_abbrev(abbrev: &str) -> Option<Self> {
+        STATIONS.into_iter().find(|s| s.to_abbrev() == abbrev)
+    }
+
+    /// Where the station's yard is on the map, in kilometers east and south
+    /// of the map's north-west corner. Positions are approximate and only
+    /// meant for drawing the stations relative to each other.
+    pub fn map_position(self) -> (f32, f32) {
+        match self {
+            Self::CitySouth => (8.6, 12.4),
+            Self::CityWest => (6.4, 11.2),
+            Self::CoalMineEast => (13.2, 5.8),
+            Self::CoalMineSouth => (10.4, 14.3),
+            Self::CoalPowerPlant => (14.6, 8.1),
+            Self::Farm => (12.1, 11.6),
+            Self::FoodFactory => (4.1, 9.7),
+            Self::ForestCentral => (8.9, 7.6),
+            Self::ForestSouth => (7.1, 9.3),
+            Self::GoodsFactory => (10.6, 9.9),
+            Self::Harbor => (2.2, 13.1),
+            Self::IronMineEast => (12.4, 2.9),
+            Self::IronMineWest => (5.7, 3.4),
+            Self::MachineFactory => (3.4, 6.1),
+            Self::MilitaryBase => (1.6, 2.4),
+            Self::OilRefinery => (1.3, 10.4),
+            Self::OilWellCentral => (6.9, 6.2),
+            Self::OilWellNorth => (8.3, 1.7),
+            Self::Sawmill => (10.2, 5.1),
+            Self::SteelMill => (9.4, 3.6),
+        }
+    }
+
+    /// The height of the station's yard above sea level, in meters.
+    ///
+    /// Heights are provisional estimates like the grades in
+    /// [`grades`](crate::grades), which have to agree with them on which way
+    /// is uphill.
+    pub fn elevation(self) -> f32 {
+        match self {
+            Self::CitySouth => 120.0,
+            Self::CityWest => 140.0,
+            Self::CoalMineEast => 390.0,
+            Self::CoalMineSouth => 180.0,
+            Self::CoalPowerPlant => 300.0,
+            Self::Farm => 210.0,
+            Self::FoodFactory => 90.0,
+            Self::ForestCentral => 360.0,
+            Self::ForestSouth => 260.0,
+            Self::GoodsFactory => 230.0,
+            Self::Harbor => 10.0,
+            Self::IronMineEast => 520.0,
+            Self::IronMineWest => 470.0,
+            Self::MachineFactory => 250.0,
+            Self::MilitaryBase => 440.0,
+            Self::OilRefinery => 20.0,
+            Self::OilWellCentral => 330.0,
+            Self::OilWellNorth => 480.0,
+            Self::Sawmill => 410.0,
+            Self::SteelMill => 450.0,
+        }
+    }
+
+    /// Splits a full track ID such as `SM-B7L` into its station and the
+    /// track within that station's yard.
+    pub fn split_track_id(track_id: &str) -> Option<(Self, String)> {
+        let (abbrev, track) = track_id.trim().split_once('-')?;
+        Some((Self::from_abbrev(abbrev)?, track.to_owned()))
+    }
 }
 
 pub const STATIONS: [Station; 20] = [
@@ -0,0 +1,190 @@
+//! A pannable, zoomable map of the stations and the track between them, with
+//! the consist's orders drawn as arrows and its planned route highlighted.
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::consist_strip::station_color;
+use crate::grades::{SEGMENTS, path};
+use crate::i18n::I18n;
+use crate::order::OrderStatus;
+use crate::route::{legs, planned_route};
+use crate::station::{STATIONS, Station};
+
+/// The width and height of the area the stations are spread over, in kilometers.
+const MAP_SIZE: f32 = 16.0;
+const STATION_RADIUS: f32 = 6.0;
+const MIN_ZOOM: f32 = 0.5;
+const MAX_ZOOM: f32 = 8.0;
+
+/// A window showing the stations on a map.
+pub struct StationMap {
+    pub open: bool,
+    /// The station clicked on this frame, if any.
+    pub clicked: Option<Station>,
+    pan: egui::Vec2,
+    zoom: f32,
+}
+
+impl Default for StationMap {
+    fn default() -> Self {
+        Self {
+            open: false,
+            clicked: None,
+            pan: egui::Vec2::ZERO,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl StationMap {
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        consist: &Consist,
+        conditions: &Conditions,
+        selected: Option<Station>,
+        i18n: &I18n,
+    ) {
+        let mut open = self.open;
+        egui::Window::new(i18n.tr("map-heading"))
+            .id(egui::Id::new("station_map_window"))
+            .open(&mut open)
+            .default_size([500.0, 500.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button(i18n.tr("map-reset")).clicked() {
+                        self.pan = egui::Vec2::ZERO;
+                        self.zoom = 1.0;
+                    }
+                    ui.label(i18n.tr("map-hint"));
+                });
+                self.map_ui(ui, consist, conditions, selected, i18n);
+            });
+        self.open = open;
+    }
+
+    fn map_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        consist: &Consist,
+        conditions: &Conditions,
+        selected: Option<Station>,
+        i18n: &I18n,
+    ) {
+        let (rect, response) =
+            ui.allocate_exact_size(ui.available_size(), egui::Sense::click_and_drag());
+        let base_scale = rect.width().min(rect.height()) / MAP_SIZE;
+
+        if response.dragged() {
+            self.pan += response.drag_delta();
+        }
+        if let Some(pointer) = response.hover_pos() {
+            let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+            if scroll != 0.0 {
+                // Keep the point under the pointer in place while zooming.
+                let center = rect.center() + self.pan;
+                let before = (pointer - center) / (base_scale * self.zoom);
+                self.zoom = (self.zoom * (scroll * 0.002).exp()).clamp(MIN_ZOOM, MAX_ZOOM);
+                let center = pointer - before * base_scale * self.zoom;
+                self.pan = center - rect.center();
+            }
+        }
+
+        let center = rect.center() + self.pan;
+        let scale = base_scale * self.zoom;
+        let to_screen = |station: Station| {
+            let (x, y) = station.map_position();
+            center + (egui::vec2(x, y) - egui::Vec2::splat(MAP_SIZE / 2.0)) * scale
+        };
+
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals();
+        painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+        // The track and the planned route along it go underneath everything
+        // else, with any leg the train would stall on in red.
+        for segment in SEGMENTS {
+            painter.line_segment(
+                [to_screen(segment.a), to_screen(segment.b)],
+                egui::Stroke::new(1.5, visuals.weak_text_color()),
+            );
+        }
+        let route = planned_route(&consist.orders);
+        let route_color = visuals.selection.bg_fill.gamma_multiply(0.6);
+        let stall_color = visuals.error_fg_color.gamma_multiply(0.8);
+        for leg in legs(consist, conditions) {
+            let points: Vec<egui::Pos2> = path(leg.from, leg.to)
+                .unwrap_or_default()
+                .into_iter()
+                .map(to_screen)
+                .collect();
+            let color = if leg.stalls() {
+                stall_color
+            } else {
+                route_color
+            };
+            painter.add(egui::Shape::line(points, egui::Stroke::new(8.0, color)));
+        }
+
+        let open_orders = consist
+            .orders
+            .iter()
+            .filter(|o| o.status != OrderStatus::Delivered);
+        for order in open_orders {
+            let from = to_screen(order.pickup_station);
+            let to = to_screen(order.dropoff_station);
+            let direction = (to - from).normalized();
+            let start = from + direction * STATION_RADIUS;
+            let end = to - direction * (STATION_RADIUS + 2.0);
+            if (end - start).length() > 0.0 {
+                painter.arrow(
+                    start,
+                    end - start,
+                    egui::Stroke::new(2.0, station_color(order.dropoff_station)),
+                );
+            }
+        }
+
+        let hovered = response.hover_pos().and_then(|pointer| {
+            STATIONS
+                .into_iter()
+                .find(|s| to_screen(*s).distance(pointer) <= STATION_RADIUS * 2.0)
+        });
+        let font = egui::FontId::proportional(12.0);
+        for station in STATIONS {
+            let pos = to_screen(station);
+            let stroke = if selected == Some(station) {
+                egui::Stroke::new(3.0, visuals.selection.stroke.color)
+            } else {
+                egui::Stroke::new(1.0, visuals.text_color())
+            };
+            painter.circle(pos, STATION_RADIUS, station_color(station), stroke);
+
+            let stops: Vec<String> = route
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| **s == station)
+                .map(|(ix, _)| (ix + 1).to_string())
+                .collect();
+            let label = if stops.is_empty() {
+                station.to_abbrev()
+            } else {
+                format!("{} ({})", station.to_abbrev(), stops.join(", "))
+            };
+            painter.text(
+                pos + egui::vec2(STATION_RADIUS + 3.0, 0.0),
+                egui::Align2::LEFT_CENTER,
+                label,
+                font.clone(),
+                visuals.strong_text_color(),
+            );
+        }
+
+        if let Some(station) = hovered {
+            if response.clicked() {
+                self.clicked = Some(station);
+            }
+            response.on_hover_text(i18n.station(station));
+        }
+    }
+}
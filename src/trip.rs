@@ -0,0 +1,264 @@
+//! Driving mode: follows the train from station to station, showing what to
+//! set out and pick up at each stop and updating the orders as it goes.
+
+use crate::conditions::Conditions;
+use crate::consist::Consist;
+use crate::history::Command;
+use crate::i18n::I18n;
+use crate::order::{Order, OrderStatus};
+use crate::route::{after_stop, legs, pick_ups, planned_route, set_outs};
+use crate::station::{STATIONS, Station};
+use crate::units::Units;
+
+/// The status changes that working a station makes: every set-out is
+/// delivered, then every pick-up goes on the train.
+pub fn status_changes(orders: &[Order], station: Station) -> Vec<Command> {
+    let delivered = set_outs(orders, station)
+        .into_iter()
+        .map(|index| Command::SetOrderStatus {
+            index,
+            old: OrderStatus::PickedUp,
+            new: OrderStatus::Delivered,
+        });
+    let picked_up = pick_ups(orders, station)
+        .into_iter()
+        .map(|index| Command::SetOrderStatus {
+            index,
+            old: OrderStatus::Planned,
+            new: OrderStatus::PickedUp,
+        });
+    delivered.chain(picked_up).collect()
+}
+
+/// A window for following the trip stop by stop.
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct TripMode {
+    pub open: bool,
+    /// The station the train is currently at.
+    pub station: Option<Station>,
+    /// The status changes made by advancing to the next stop, for the app to apply.
+    #[serde(skip)]
+    pub command: Option<Command>,
+}
+
+impl TripMode {
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        consist: &Consist,
+        conditions: &Conditions,
+        i18n: &I18n,
+        units: &Units,
+    ) {
+        let mut open = self.open;
+        egui::Window::new(i18n.tr("trip-heading"))
+            .id(egui::Id::new("trip_window"))
+            .open(&mut open)
+            .default_width(350.0)
+            .show(ctx, |ui| {
+                self.stepper_ui(ui, consist, i18n);
+                profile_ui(ui, consist, conditions, i18n, units);
+                ui.separator();
+                match self.station {
+                    Some(station) => {
+                        self.stop_ui(ui, consist, conditions, i18n, units, station);
+                    }
+                    None => {
+                        ui.label(i18n.tr("trip-start-hint"));
+                    }
+                }
+            });
+        self.open = open;
+    }
+
+    // The current station, with buttons to step along the planned route.
+    fn stepper_ui(&mut self, ui: &mut egui::Ui, consist: &Consist, i18n: &I18n) {
+        let route = planned_route(&consist.orders);
+        let position = self
+            .station
+            .and_then(|station| route.iter().position(|s| *s == station));
+        let previous = position
+            .and_then(|ix| ix.checked_sub(1))
+            .and_then(|ix| route.get(ix));
+        let next = position.map_or(route.first(), |ix| route.get(ix + 1));
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(previous.is_some(), egui::Button::new("⏴"))
+                .on_hover_text(i18n.tr("trip-previous"))
+                .clicked()
+            {
+                self.station = previous.copied();
+            }
+            egui::ComboBox::from_id_salt("trip_station")
+                .width(180.0)
+                .selected_text(
+                    self.station
+                        .map_or_else(|| i18n.tr("trip-not-started"), |s| i18n.station(s)),
+                )
+                .show_ui(ui, |ui| {
+                    for s in STATIONS {
+                        ui.selectable_value(&mut self.station, Some(s), i18n.station(s));
+                    }
+                });
+            if ui
+                .add_enabled(next.is_some(), egui::Button::new("⏵"))
+                .on_hover_text(i18n.tr("trip-next"))
+                .clicked()
+            {
+                self.station = next.copied();
+            }
+        });
+        if !route.is_empty() {
+            let stops: Vec<String> = route.iter().map(|s| s.to_abbrev()).collect();
+            ui.label(i18n.tr_args("trip-route", &[("stops", stops.join(" → "))]));
+        }
+    }
+
+    fn stop_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        consist: &Consist,
+        conditions: &Conditions,
+        i18n: &I18n,
+        units: &Units,
+        station: Station,
+    ) {
+        let set_outs = set_outs(&consist.orders, station);
+        let pick_ups = pick_ups(&consist.orders, station);
+
+        let stop = [("station", station.to_abbrev())];
+        ui.strong(i18n.tr_args("trip-set-out", &stop));
+        if set_outs.is_empty() {
+            ui.label(i18n.tr("trip-nothing-to-set-out"));
+        }
+        for order in set_outs.iter().filter_map(|ix| consist.orders.get(*ix)) {
+            ui.label(i18n.tr_args(
+                "trip-set-out-order",
+                &[
+                    ("order", order.name.clone()),
+                    ("cars", order.car_count.to_string()),
+                    (
+                        "track",
+                        format!(
+                            "{}-{}",
+                            order.dropoff_station.to_abbrev(),
+                            order.dropoff_track
+                        ),
+                    ),
+                ],
+            ));
+        }
+        ui.add_space(5.0);
+        ui.strong(i18n.tr_args("trip-pick-up", &stop));
+        if pick_ups.is_empty() {
+            ui.label(i18n.tr("trip-nothing-to-pick-up"));
+        }
+        for order in pick_ups.iter().filter_map(|ix| consist.orders.get(*ix)) {
+            ui.label(i18n.tr_args(
+                "trip-pick-up-order",
+                &[
+                    ("order", order.name.clone()),
+                    ("cars", order.car_count.to_string()),
+                    (
+                        "track",
+                        format!(
+                            "{}-{}",
+                            order.pickup_station.to_abbrev(),
+                            order.pickup_track
+                        ),
+                    ),
+                ],
+            ));
+        }
+
+        ui.separator();
+        let next = after_stop(consist, station);
+        let next_stop = planned_route(&next.orders)
+            .into_iter()
+            .find(|s| *s != station);
+        let summary = next.summary(conditions.coupler_limit);
+        match next_stop {
+            Some(stop) => {
+                ui.strong(i18n.tr_args("trip-next-leg", &[("station", i18n.station(stop))]))
+            }
+            None => ui.strong(i18n.tr("trip-last-leg")),
+        };
+        ui.label(i18n.tr_args(
+            "trip-weight",
+            &[("weight", units.mass(summary.total_weight))],
+        ));
+        ui.label(i18n.tr_args(
+            "trip-length",
+            &[("length", units.length(summary.total_length))],
+        ));
+        for warning in &summary.warnings {
+            let text = format!("- {}", i18n.warning(warning, units));
+            ui.colored_label(ui.visuals().warn_fg_color, text);
+        }
+
+        let changes = status_changes(&consist.orders, station);
+        let can_advance = !changes.is_empty() || next_stop.is_some();
+        if ui
+            .add_enabled(can_advance, egui::Button::new(i18n.tr("trip-advance")))
+            .clicked()
+        {
+            if !changes.is_empty() {
+                self.command = Some(Command::Batch(changes));
+            }
+            if next_stop.is_some() {
+                self.station = next_stop;
+            }
+        }
+    }
+}
+
+// Each leg of the planned route with its ruling grade and whether the train
+// can get up it, marking the first leg it would stall on.
+fn profile_ui(
+    ui: &mut egui::Ui,
+    consist: &Consist,
+    conditions: &Conditions,
+    i18n: &I18n,
+    units: &Units,
+) {
+    let legs = legs(consist, conditions);
+    if legs.is_empty() {
+        return;
+    }
+    let stall = legs.iter().position(|leg| leg.stalls());
+    egui::CollapsingHeader::new(i18n.tr("trip-profile"))
+        .id_salt("route_profile")
+        .default_open(stall.is_some())
+        .show(ui, |ui| {
+            egui::Grid::new("route_profile")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (ix, leg) in legs.iter().enumerate() {
+                        ui.label(format!("{} → {}", leg.from.to_abbrev(), leg.to.to_abbrev()));
+                        ui.label(format!("↑ {:.1}%", leg.ruling_grade));
+                        ui.label(format!(
+                            "{} / {}",
+                            units.mass(leg.weight),
+                            units.mass(leg.supported)
+                        ));
+                        if stall == Some(ix) {
+                            ui.colored_label(
+                                ui.visuals().error_fg_color,
+                                i18n.tr("trip-profile-stalls"),
+                            );
+                        } else if leg.stalls() {
+                            ui.colored_label(
+                                ui.visuals().warn_fg_color,
+                                i18n.tr("trip-profile-too-heavy"),
+                            );
+                        } else {
+                            ui.label("");
+                        }
+                        ui.end_row();
+                    }
+                });
+            ui.weak(i18n.tr("grades-provisional"));
+        });
+}
@@ -0,0 +1,239 @@
+//! Typed weights and lengths, and the units they are shown and entered in.
+//!
+//! Quantities are always stored in metric tonnes and meters, the units the
+//! game uses, and only converted when they are shown to or read from the user.
+
+use std::fmt::{self, Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+use crate::i18n::I18n;
+
+const TONNES_PER_SHORT_TON: f32 = 0.907_184_7;
+const METERS_PER_FOOT: f32 = 0.3048;
+
+/// A weight, stored in metric tonnes.
+#[derive(
+    Clone, Copy, Default, PartialEq, PartialOrd, Debug, serde::Deserialize, serde::Serialize,
+)]
+#[serde(transparent)]
+pub struct Mass(f32);
+
+impl Mass {
+    pub const ZERO: Self = Self(0.0);
+
+    pub const fn from_tonnes(tonnes: f32) -> Self {
+        Self(tonnes)
+    }
+
+    pub const fn tonnes(self) -> f32 {
+        self.0
+    }
+
+    pub fn from_unit(value: f32, unit: MassUnit) -> Self {
+        match unit {
+            MassUnit::Tonnes => Self(value),
+            MassUnit::ShortTons => Self(value * TONNES_PER_SHORT_TON),
+        }
+    }
+
+    pub fn in_unit(self, unit: MassUnit) -> f32 {
+        match unit {
+            MassUnit::Tonnes => self.0,
+            MassUnit::ShortTons => self.0 / TONNES_PER_SHORT_TON,
+        }
+    }
+
+    pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// A length, stored in meters.
+#[derive(
+    Clone, Copy, Default, PartialEq, PartialOrd, Debug, serde::Deserialize, serde::Serialize,
+)]
+#[serde(transparent)]
+pub struct Length(f32);
+
+impl Length {
+    pub const ZERO: Self = Self(0.0);
+
+    pub const fn from_meters(meters: f32) -> Self {
+        Self(meters)
+    }
+
+    /// Lengths in the game's data files are given in millimeters.
+    pub const fn from_millimeters(millimeters: f32) -> Self {
+        Self(millimeters / 1000.0)
+    }
+
+    pub const fn meters(self) -> f32 {
+        self.0
+    }
+
+    pub fn from_unit(value: f32, unit: LengthUnit) -> Self {
+        match unit {
+            LengthUnit::Meters => Self(value),
+            LengthUnit::Feet => Self(value * METERS_PER_FOOT),
+        }
+    }
+
+    pub fn in_unit(self, unit: LengthUnit) -> f32 {
+        match unit {
+            LengthUnit::Meters => self.0,
+            LengthUnit::Feet => self.0 / METERS_PER_FOOT,
+        }
+    }
+
+    pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+macro_rules! impl_quantity_ops {
+    ($quantity:ident) => {
+        impl Add for $quantity {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $quantity {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $quantity {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul<f32> for $quantity {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Sum for $quantity {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+    };
+}
+
+impl_quantity_ops!(Mass);
+impl_quantity_ops!(Length);
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum MassUnit {
+    #[default]
+    Tonnes,
+    ShortTons,
+}
+
+impl Display for MassUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let unit_str = match self {
+            Self::Tonnes => "Metric tonnes",
+            Self::ShortTons => "US short tons",
+        };
+        write!(f, "{unit_str}")
+    }
+}
+
+impl MassUnit {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Tonnes => "T",
+            Self::ShortTons => "tn",
+        }
+    }
+}
+
+pub const MASS_UNITS: [MassUnit; 2] = [MassUnit::Tonnes, MassUnit::ShortTons];
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+pub enum LengthUnit {
+    #[default]
+    Meters,
+    Feet,
+}
+
+impl Display for LengthUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let unit_str = match self {
+            Self::Meters => "Meters",
+            Self::Feet => "Feet",
+        };
+        write!(f, "{unit_str}")
+    }
+}
+
+impl LengthUnit {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Meters => "m",
+            Self::Feet => "ft",
+        }
+    }
+}
+
+pub const LENGTH_UNITS: [LengthUnit; 2] = [LengthUnit::Meters, LengthUnit::Feet];
+
+/// The units the user wants to see and enter weights and lengths in.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Units {
+    pub mass: MassUnit,
+    pub length: LengthUnit,
+}
+
+impl Units {
+    /// Formats a weight in the preferred unit, e.g. `312.50 T`.
+    pub fn mass(&self, mass: Mass) -> String {
+        format!("{:.2} {}", mass.in_unit(self.mass), self.mass.symbol())
+    }
+
+    /// Formats a length in the preferred unit, e.g. `74.50 m`.
+    pub fn length(&self, length: Length) -> String {
+        format!(
+            "{:.2} {}",
+            length.in_unit(self.length),
+            self.length.symbol()
+        )
+    }
+
+    /// Combo boxes for picking the preferred units.
+    pub fn picker_ui(&mut self, ui: &mut egui::Ui, i18n: &I18n) {
+        egui::ComboBox::from_id_salt("mass_unit")
+            .selected_text(self.mass.symbol())
+            .width(50.0)
+            .show_ui(ui, |ui| {
+                for unit in MASS_UNITS {
+                    ui.selectable_value(&mut self.mass, unit, i18n.mass_unit(unit));
+                }
+            })
+            .response
+            .on_hover_text(i18n.tr("units-mass"));
+        egui::ComboBox::from_id_salt("length_unit")
+            .selected_text(self.length.symbol())
+            .width(50.0)
+            .show_ui(ui, |ui| {
+                for unit in LENGTH_UNITS {
+                    ui.selectable_value(&mut self.length, unit, i18n.length_unit(unit));
+                }
+            })
+            .response
+            .on_hover_text(i18n.tr("units-length"));
+    }
+}
@@ -0,0 +1,60 @@
+use std::process::{Command, Output};
+
+// Runs the CLI on a fixture with the given options.
+fn run(options: &[&str], fixture: &str) -> Output {
+    let path = format!("{}/tests/fixtures/{fixture}", env!("CARGO_MANIFEST_DIR"));
+    Command::new(env!("CARGO_BIN_EXE_dv_helper-cli"))
+        .args(options)
+        .arg(path)
+        .output()
+        .expect("The CLI runs")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "The CLI succeeds: {output:?}");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn prints_a_report() {
+    let report = stdout(&run(&[], "consist_de6_s282.json"));
+    for line in [
+        "Total Weight: 299.80 T",
+        "Total Length: 40.82 m",
+        "  0% grade: 6000.00 T",
+        "  2% grade: 2200.00 T",
+        "Max Speed: 100 km/h (S282)",
+    ] {
+        assert!(
+            report.lines().any(|l| l == line),
+            "{line:?} is in the report:\n{report}"
+        );
+    }
+}
+
+#[test]
+fn applies_a_coupler_limit() {
+    let report = stdout(&run(&["--coupler-limit", "4000"], "consist_de6_s282.json"));
+    assert!(
+        report.lines().any(|l| l == "  0% grade: 4000.00 T"),
+        "The head end is limited by its coupler:\n{report}"
+    );
+
+    let json = stdout(&run(
+        &["--json", "--coupler-limit", "2000"],
+        "consist_de6_s282.json",
+    ));
+    let summary: serde_json::Value = serde_json::from_str(&json).expect("The report is JSON");
+    assert_eq!(
+        summary.pointer("/supported_weights/two_grade"),
+        Some(&2000.into()),
+        "The limit caps the 2% rating too"
+    );
+}
+
+#[test]
+fn rejects_a_bad_coupler_limit() {
+    let output = run(&["--coupler-limit", "heavy"], "consist_de6_s282.json");
+    assert_eq!(output.status.code(), Some(2), "A usage error");
+    assert!(output.stdout.is_empty(), "No report is printed");
+}
@@ -0,0 +1,37 @@
+//! Fixtures shared by the integration tests. Not every test uses all of
+//! them.
+#![allow(dead_code)]
+
+use dv_helper::locomotive::{Locomotive, LocomotiveInfo, locomotives};
+use dv_helper::order::{Order, OrderStatus};
+use dv_helper::station::Station;
+use dv_helper::units::{Length, Mass};
+
+pub fn loco(loco: Locomotive) -> LocomotiveInfo {
+    locomotives()
+        .get(&loco)
+        .expect("Every locomotive has info")
+        .clone()
+}
+
+/// A one-car order on the train from the Steel Mill to the Harbor. Tests
+/// change the fields they care about with `..order(weight, length)`.
+pub fn order(weight: f32, length: f32) -> Order {
+    Order {
+        name: "Test".to_owned(),
+        job_id: String::new(),
+        car_count: 1,
+        car_type: None,
+        cargo: String::new(),
+        hazmat: None,
+        weight: Mass::from_tonnes(weight),
+        length: Length::from_meters(length),
+        pickup_station: Station::SteelMill,
+        pickup_track: String::new(),
+        dropoff_station: Station::Harbor,
+        dropoff_track: String::new(),
+        status: OrderStatus::PickedUp,
+        speed_limit_kmh: None,
+        payout: None,
+    }
+}
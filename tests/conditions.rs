@@ -0,0 +1,51 @@
+mod common;
+
+use common::loco;
+use dv_helper::conditions::{Conditions, Verdict, Weather};
+use dv_helper::consist::Consist;
+use dv_helper::locomotive::Locomotive;
+
+fn conditions(grade: f32, weather: Weather) -> Conditions {
+    Conditions {
+        grade,
+        weather,
+        ..Conditions::default()
+    }
+}
+
+#[test]
+fn matches_the_rated_weights() {
+    for l in [Locomotive::DE2, Locomotive::DH4, Locomotive::S282] {
+        let info = loco(l);
+        let at = |grade, weather| conditions(grade, weather).capacity(&info);
+        assert!((at(0.0, Weather::Dry) - f32::from(info.zero_grade_t)).abs() < 0.5);
+        assert!((at(2.0, Weather::Dry) - f32::from(info.two_grade_t)).abs() < 0.5);
+        assert!((at(2.0, Weather::Rain) - f32::from(info.rain_grade_t)).abs() < 0.5);
+        let one = at(1.0, Weather::Dry);
+        assert!(one < f32::from(info.zero_grade_t) && one > f32::from(info.two_grade_t));
+        assert!(at(3.0, Weather::Dry) < at(2.0, Weather::Dry));
+    }
+}
+
+#[test]
+fn weather_and_sanding_change_the_verdict() {
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2)],
+        orders: Vec::new(),
+        jobs: Vec::new(),
+    };
+    let leaves = conditions(2.0, Weather::Leaves);
+    let sanded = Conditions {
+        sanding: true,
+        ..leaves
+    };
+    let slippery = consist.supported_weight(&leaves).tonnes();
+    let with_sand = consist.supported_weight(&sanded).tonnes();
+    assert!(slippery < with_sand && with_sand < 300.0);
+    assert_eq!(
+        Verdict::new(200.0, slippery),
+        Verdict::TooHeavy { excess: 50.0 }
+    );
+    assert!(matches!(Verdict::new(200.0, with_sand), Verdict::Ok { .. }));
+    assert_eq!(Verdict::new(200.0, 0.0), Verdict::NoPower);
+}
@@ -0,0 +1,252 @@
+mod common;
+
+use common::{loco, order};
+use dv_helper::conditions::{Conditions, DEFAULT_COUPLER_LIMIT};
+use dv_helper::consist::{Consist, ConsistWarning, Rating, TrainUnit};
+use dv_helper::locomotive::{Locomotive, Placement};
+use dv_helper::order::OrderStatus;
+use dv_helper::speed::{HAZMAT_SPEED_KMH, SpeedLimiter};
+use dv_helper::units::Mass;
+
+fn assert_close(actual: f32, expected: f32, what: &str) {
+    assert!(
+        (actual - expected).abs() < 0.01,
+        "{what}: expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn empty_consist_has_no_warnings() {
+    let summary = Consist::default().summary(None);
+    assert_close(summary.total_weight.tonnes(), 0.0, "Total weight");
+    assert_close(summary.total_length.meters(), 0.0, "Total length");
+    assert!(summary.warnings.is_empty(), "No warnings");
+}
+
+#[test]
+fn sums_weights_and_lengths() {
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2), loco(Locomotive::Caboose)],
+        orders: vec![order(100.0, 30.0), order(50.5, 14.3)],
+        jobs: Vec::new(),
+    };
+    let summary = consist.summary(None);
+    assert_close(summary.loco_weight.tonnes(), 60.0, "Locomotive weight");
+    assert_close(summary.order_weight.tonnes(), 150.5, "Order weight");
+    assert_close(summary.total_weight.tonnes(), 210.5, "Total weight");
+    assert_close(summary.loco_length.meters(), 20.8, "Locomotive length");
+    assert_close(summary.order_length.meters(), 44.3, "Order length");
+    assert_close(summary.total_length.meters(), 65.1, "Total length");
+}
+
+#[test]
+fn only_counts_orders_on_the_train() {
+    let mut planned = order(100.0, 30.0);
+    planned.status = OrderStatus::Planned;
+    let mut delivered = order(200.0, 40.0);
+    delivered.status = OrderStatus::Delivered;
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2)],
+        orders: vec![planned, order(50.0, 10.0), delivered],
+        jobs: Vec::new(),
+    };
+    let summary = consist.summary(None);
+    assert_close(summary.order_weight.tonnes(), 50.0, "Order weight");
+    assert_close(summary.planned_weight.tonnes(), 100.0, "Planned weight");
+    assert_close(summary.order_length.meters(), 10.0, "Order length");
+}
+
+#[test]
+fn only_powered_locomotives_add_capacity() {
+    let mut de6 = loco(Locomotive::DE6);
+    de6.powered = false;
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2), de6, loco(Locomotive::DE6Slug)],
+        orders: Vec::new(),
+        jobs: Vec::new(),
+    };
+    let supported = consist.supported_weights(None);
+    assert_eq!(supported.get(Rating::ZeroGrade), 1200, "0% grade");
+    assert_eq!(supported.get(Rating::TwoGrade), 300, "2% grade");
+    assert_eq!(supported.get(Rating::TwoGradeRain), 250, "2% grade in rain");
+}
+
+#[test]
+fn warns_when_overweight() {
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2)],
+        orders: vec![order(250.0, 50.0)],
+        jobs: Vec::new(),
+    };
+    assert_eq!(
+        consist.summary(None).warnings,
+        [ConsistWarning::Overweight {
+            rating: Rating::TwoGradeRain,
+            weight: Mass::from_tonnes(288.0),
+            supported: 250,
+        }],
+        "Only the rain rating is exceeded"
+    );
+}
+
+#[test]
+fn warns_without_power() {
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::Caboose)],
+        orders: vec![order(100.0, 30.0)],
+        jobs: Vec::new(),
+    };
+    assert_eq!(
+        consist.summary(None).warnings,
+        [ConsistWarning::NoPoweredLocomotive],
+        "No powered locomotive"
+    );
+}
+
+#[test]
+fn groups_identical_neighbouring_locomotives() {
+    let mut unpowered = loco(Locomotive::DE6);
+    unpowered.powered = false;
+    let consist = Consist {
+        locomotives: vec![
+            loco(Locomotive::DE6),
+            loco(Locomotive::DE6),
+            unpowered,
+            loco(Locomotive::DE6Slug),
+            loco(Locomotive::DE6),
+        ],
+        orders: Vec::new(),
+        jobs: Vec::new(),
+    };
+    assert_eq!(consist.locomotive_groups(), vec![0..2, 2..3, 3..4, 4..5]);
+    assert_eq!(consist.locomotive_group(1), Some(0..2));
+    assert_eq!(consist.locomotive_group(5), None);
+}
+
+#[test]
+fn places_locomotives_between_the_cuts() {
+    let mut helper = loco(Locomotive::DH4);
+    helper.placement = Placement::AfterCut(1);
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::Caboose), helper, loco(Locomotive::DE2)],
+        orders: vec![order(100.0, 30.0), order(50.0, 10.0)],
+        jobs: Vec::new(),
+    };
+    let names: Vec<String> = consist
+        .units()
+        .into_iter()
+        .map(|unit| match unit {
+            TrainUnit::Locomotive(l) => l.loco.to_string(),
+            TrainUnit::Cut(o) => format!("{}t", o.weight.tonnes()),
+        })
+        .collect();
+    assert_eq!(names, ["DE2", "100t", "DH4", "50t", "Caboose"]);
+}
+
+#[test]
+fn distributed_power_shares_the_coupler_load() {
+    let head_end = Consist {
+        locomotives: vec![loco(Locomotive::DE6), loco(Locomotive::DE6)],
+        orders: vec![order(1000.0, 100.0), order(1000.0, 100.0)],
+        jobs: Vec::new(),
+    };
+    let limit = Some(DEFAULT_COUPLER_LIMIT);
+    let summary = head_end.summary(limit);
+    assert_eq!(summary.power_positions, 1);
+    assert_eq!(
+        summary.supported_weights.get(Rating::ZeroGrade),
+        4000,
+        "Limited by the coupler behind the head end"
+    );
+    assert_eq!(summary.supported_weights.get(Rating::TwoGrade), 2400);
+
+    // The limit is an assumption the player can change.
+    let weaker = Some(Mass::from_tonnes(2000.0));
+    assert_eq!(
+        head_end
+            .summary(weaker)
+            .supported_weights
+            .get(Rating::TwoGrade),
+        2000,
+        "A weaker coupler caps the 2% rating too"
+    );
+    let conditions = Conditions {
+        grade: 0.0,
+        coupler_limit: weaker,
+        ..Conditions::default()
+    };
+    assert_close(
+        head_end.supported_weight(&conditions).tonnes(),
+        2000.0,
+        "The coupler limit in the conditions",
+    );
+
+    let mut distributed = head_end;
+    if let Some(last) = distributed.locomotives.last_mut() {
+        last.placement = Placement::AfterCut(1);
+    }
+    let summary = distributed.summary(limit);
+    assert_eq!(summary.power_positions, 2);
+    assert_eq!(summary.supported_weights.get(Rating::ZeroGrade), 6000);
+}
+
+#[test]
+fn couplers_are_unlimited_by_default() {
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE6), loco(Locomotive::S282)],
+        orders: vec![order(1000.0, 100.0)],
+        jobs: Vec::new(),
+    };
+    assert_eq!(
+        Conditions::default().coupler_limit,
+        None,
+        "The coupler limit is an estimate, so it's off by default"
+    );
+    assert_eq!(
+        consist
+            .summary(None)
+            .supported_weights
+            .get(Rating::ZeroGrade),
+        6000,
+        "The ratings add up as they did before the coupler limit"
+    );
+    let level = Conditions {
+        grade: 0.0,
+        ..Conditions::default()
+    };
+    assert_close(
+        consist.supported_weight(&level).tonnes(),
+        6000.0,
+        "On the level the pull is the sum of the ratings",
+    );
+}
+
+#[test]
+fn finds_what_limits_the_speed() {
+    let mut hazmat = order(100.0, 30.0);
+    hazmat.name = "Ammonia".to_owned();
+    hazmat.cargo = "Ammonia".to_owned();
+    let mut consist = Consist {
+        locomotives: vec![loco(Locomotive::S282)],
+        orders: vec![order(100.0, 30.0), hazmat],
+        jobs: Vec::new(),
+    };
+    let summary = consist.summary(None);
+    let limit = summary.max_speed.expect("A limit");
+    assert_eq!(limit.kmh, HAZMAT_SPEED_KMH);
+    assert_eq!(limit.limiter, SpeedLimiter::Hazmat("Ammonia".to_owned()));
+    assert!(summary.warnings.is_empty(), "Hazmat limits are no surprise");
+
+    if let Some(s282) = consist.locomotives.first_mut() {
+        s282.reversed = true;
+    }
+    assert_eq!(
+        consist.summary(None).warnings,
+        [ConsistWarning::SlowLocomotive {
+            loco: Locomotive::S282,
+            reversed: true,
+            kmh: 50,
+        }],
+        "Running tender first caps the train"
+    );
+}
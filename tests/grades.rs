@@ -0,0 +1,103 @@
+mod common;
+
+use dv_helper::conditions::Conditions;
+use dv_helper::consist::Consist;
+use dv_helper::grades::{SEGMENTS, path, ruling_grade};
+use dv_helper::locomotive::{Locomotive, locomotives};
+use dv_helper::order::{Order, OrderStatus};
+use dv_helper::route::legs;
+use dv_helper::station::{STATIONS, Station};
+
+fn order(weight: f32, pickup_station: Station, dropoff_station: Station) -> Order {
+    Order {
+        pickup_station,
+        dropoff_station,
+        status: OrderStatus::Planned,
+        ..common::order(weight, 50.0)
+    }
+}
+
+#[test]
+fn every_station_is_connected() {
+    for station in STATIONS {
+        let route = path(Station::Harbor, station).expect("A path to every station");
+        assert_eq!(route.first(), Some(&Station::Harbor));
+        assert_eq!(route.last(), Some(&station));
+    }
+}
+
+#[test]
+fn ruling_grade_depends_on_direction() {
+    let down = ruling_grade(Station::CityWest, Station::Harbor).expect("Connected");
+    let up = ruling_grade(Station::Harbor, Station::CityWest).expect("Connected");
+    assert!(
+        up > down,
+        "Climbing from the harbor is harder: {up} vs {down}"
+    );
+}
+
+#[test]
+fn flags_the_leg_that_stalls() {
+    let de2 = locomotives()
+        .get(&Locomotive::DE2)
+        .expect("Every locomotive has info")
+        .clone();
+    let consist = Consist {
+        locomotives: vec![de2],
+        orders: vec![
+            order(100.0, Station::Harbor, Station::CityWest),
+            order(300.0, Station::CityWest, Station::ForestSouth),
+        ],
+        jobs: Vec::new(),
+    };
+    let legs = legs(&consist, &Conditions::default());
+    let stops: Vec<(Station, Station)> = legs.iter().map(|l| (l.from, l.to)).collect();
+    assert_eq!(
+        stops,
+        [
+            (Station::Harbor, Station::CityWest),
+            (Station::CityWest, Station::ForestSouth)
+        ]
+    );
+    let stalls: Vec<bool> = legs.iter().map(|l| l.stalls()).collect();
+    assert_eq!(stalls, [false, true], "Only the heavy climb stalls");
+}
+
+#[test]
+fn grades_agree_with_the_elevations() {
+    for segment in SEGMENTS {
+        let climb = segment.b.elevation() - segment.a.elevation();
+        let (up, down) = if climb > 0.0 {
+            (segment.grade_ab, segment.grade_ba)
+        } else {
+            (segment.grade_ba, segment.grade_ab)
+        };
+        assert!(
+            up >= down,
+            "{:?} - {:?} climbs {climb} m but is steeper the other way",
+            segment.a,
+            segment.b
+        );
+    }
+}
+
+#[test]
+fn follows_the_line_on_the_map() {
+    let abbrevs = |from, to| {
+        path(from, to)
+            .expect("Connected")
+            .iter()
+            .map(|s| s.to_abbrev())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        abbrevs(Station::Harbor, Station::FoodFactory),
+        ["HB", "OR", "FF"],
+        "From the harbor the line runs past the refinery"
+    );
+    assert_eq!(
+        abbrevs(Station::MilitaryBase, Station::SteelMill),
+        ["MB", "IMW", "OWN", "SM"],
+        "The northern line runs through the iron mine and oil wells"
+    );
+}
@@ -0,0 +1,205 @@
+mod common;
+
+use common::{loco, order};
+use dv_helper::consist::Consist;
+use dv_helper::history::{Command, History, MAX_HISTORY};
+use dv_helper::locomotive::{Locomotive, Placement};
+use dv_helper::order::{Order, OrderStatus};
+
+fn named(name: &str) -> Order {
+    Order {
+        name: name.to_owned(),
+        ..order(100.0, 30.0)
+    }
+}
+
+fn consist() -> Consist {
+    Consist {
+        locomotives: vec![loco(Locomotive::DE2), loco(Locomotive::DE6)],
+        orders: vec![named("A"), named("B")],
+        jobs: vec![named("Job")],
+    }
+}
+
+// Applies the command, then checks that undo gets back to where it started
+// and redo to where the command left it.
+fn assert_round_trip(command: Command) {
+    let before = consist();
+    let mut edited = before.clone();
+    let mut history = History::default();
+    history.execute(command, &mut edited);
+    let after = edited.clone();
+    assert!(after != before, "The command changes the consist");
+    assert!(history.undo(&mut edited), "The command can be undone");
+    assert!(edited == before, "Undo restores the consist");
+    assert!(history.redo(&mut edited), "The command can be redone");
+    assert!(edited == after, "Redo applies the command again");
+}
+
+#[test]
+fn every_command_undoes_and_redoes() {
+    let mut edited = named("A");
+    edited.weight = order(50.0, 30.0).weight;
+    let commands = [
+        Command::AddLocomotive {
+            index: 1,
+            loco: loco(Locomotive::DH4),
+        },
+        Command::DeleteLocomotive {
+            index: 0,
+            loco: loco(Locomotive::DE2),
+        },
+        Command::TogglePower { index: 0 },
+        Command::ToggleReversed { index: 1 },
+        Command::MoveLocomotive { from: 0, to: 1 },
+        Command::SetPlacement {
+            index: 1,
+            old: Placement::Head,
+            new: Placement::Rear,
+        },
+        Command::AddOrder {
+            index: 1,
+            order: named("C"),
+        },
+        Command::EditOrder {
+            index: 0,
+            old: named("A"),
+            new: edited,
+        },
+        Command::DeleteOrder {
+            index: 1,
+            order: named("B"),
+        },
+        Command::MoveOrder { from: 1, to: 0 },
+        Command::SetOrderStatus {
+            index: 0,
+            old: OrderStatus::PickedUp,
+            new: OrderStatus::Delivered,
+        },
+        Command::AddJob {
+            index: 0,
+            job: named("Another job"),
+        },
+        Command::DeleteJob {
+            index: 0,
+            job: named("Job"),
+        },
+        Command::TakeJob { job: 0, order: 2 },
+        Command::ReturnJob { order: 0, job: 1 },
+        Command::Batch(vec![
+            Command::TogglePower { index: 1 },
+            Command::MoveOrder { from: 0, to: 1 },
+        ]),
+    ];
+    for command in commands {
+        assert_round_trip(command);
+    }
+}
+
+#[test]
+fn batches_undo_in_reverse() {
+    let mut edited = consist();
+    let mut history = History::default();
+    // Adding C at the front and then moving it behind A only undoes
+    // cleanly if the move is undone first.
+    history.execute(
+        Command::Batch(vec![
+            Command::AddOrder {
+                index: 0,
+                order: named("C"),
+            },
+            Command::MoveOrder { from: 0, to: 1 },
+        ]),
+        &mut edited,
+    );
+    let names = |c: &Consist| c.orders.iter().map(|o| o.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(&edited), ["A", "C", "B"]);
+    assert!(history.undo(&mut edited), "The batch can be undone");
+    assert_eq!(names(&edited), ["A", "B"]);
+}
+
+#[test]
+fn keeps_a_bounded_history() {
+    let mut edited = consist();
+    let mut history = History::default();
+    for _ in 0..=MAX_HISTORY {
+        history.execute(Command::TogglePower { index: 0 }, &mut edited);
+    }
+    let mut undone = 0;
+    while history.undo(&mut edited) {
+        undone += 1;
+    }
+    assert_eq!(undone, MAX_HISTORY);
+    assert!(
+        edited.locomotives.first().is_some_and(|l| !l.powered),
+        "The oldest toggle can no longer be undone"
+    );
+}
+
+#[test]
+fn ignores_indices_that_have_gone_stale() {
+    let mut edited = consist();
+    let mut history = History::default();
+    for command in [
+        Command::DeleteOrder {
+            index: 5,
+            order: named("Gone"),
+        },
+        Command::MoveOrder { from: 7, to: 0 },
+        Command::MoveLocomotive { from: 0, to: 9 },
+        Command::TakeJob { job: 3, order: 0 },
+        Command::AddOrder {
+            index: 10,
+            order: named("C"),
+        },
+    ] {
+        history.execute(command, &mut edited);
+    }
+    assert!(edited == consist(), "Stale commands change nothing");
+    assert!(!history.can_undo(), "Nor are they recorded");
+}
+
+#[test]
+fn records_only_the_part_of_a_batch_that_applied() {
+    let mut edited = consist();
+    let mut history = History::default();
+    // The move's indices have gone stale, so undoing the batch must not
+    // run it backwards and reorder the orders.
+    history.execute(
+        Command::Batch(vec![
+            Command::TogglePower { index: 0 },
+            Command::MoveOrder { from: 1, to: 6 },
+            Command::DeleteJob {
+                index: 0,
+                job: named("Job"),
+            },
+        ]),
+        &mut edited,
+    );
+    let after = edited.clone();
+    assert!(
+        edited.jobs.is_empty() && edited.locomotives.first().is_some_and(|l| !l.powered),
+        "The commands that fit are applied"
+    );
+    assert!(history.undo(&mut edited), "The batch can be undone");
+    assert!(edited == consist(), "Undo restores the consist");
+    assert!(history.redo(&mut edited), "The batch can be redone");
+    assert!(edited == after, "Redo applies the same commands again");
+
+    history.execute(
+        Command::Batch(vec![
+            Command::DeleteOrder {
+                index: 4,
+                order: named("Gone"),
+            },
+            Command::TogglePower { index: 8 },
+        ]),
+        &mut edited,
+    );
+    assert!(edited == after, "A wholly stale batch changes nothing");
+    assert!(
+        history.undo(&mut edited),
+        "Only the first batch was recorded"
+    );
+    assert!(!history.can_undo(), "The stale batch wasn't recorded");
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use dv_helper::i18n::{I18n, LANGUAGES, Language, message_ids, station_key};
+use dv_helper::station::STATIONS;
+
+#[test]
+fn every_catalog_has_every_key() {
+    let english: BTreeSet<String> = message_ids(Language::English.catalog())
+        .into_iter()
+        .collect();
+    assert!(!english.is_empty());
+    for language in LANGUAGES {
+        let ids = message_ids(language.catalog());
+        let keys: BTreeSet<String> = ids.iter().cloned().collect();
+        assert_eq!(keys.len(), ids.len(), "{language} has duplicate keys");
+        let missing: Vec<&String> = english.difference(&keys).collect();
+        let extra: Vec<&String> = keys.difference(&english).collect();
+        assert!(missing.is_empty(), "{language} is missing {missing:?}");
+        assert!(extra.is_empty(), "{language} has unknown keys {extra:?}");
+    }
+}
+
+#[test]
+fn every_station_is_translated() {
+    let english: BTreeSet<String> = message_ids(Language::English.catalog())
+        .into_iter()
+        .collect();
+    for station in STATIONS {
+        assert!(english.contains(&station_key(station)), "{station}");
+    }
+    let i18n = I18n::new(Language::English);
+    for station in STATIONS {
+        assert_eq!(i18n.station(station), station.to_string());
+    }
+}
+
+#[test]
+fn falls_back_to_english_then_the_key() {
+    let i18n = I18n::new(Language::German);
+    assert_eq!(i18n.tr("menu-undo"), "Rückgängig");
+    assert_eq!(
+        i18n.tr_args("delivered-heading", &[("count", "3".to_owned())]),
+        "Zugestellt (3)"
+    );
+    assert_eq!(i18n.tr("no-such-key"), "no-such-key");
+}
+
+// Each single-line message's source text, by its ID.
+fn sources(catalog: &str) -> BTreeMap<&str, &str> {
+    catalog
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once(" = "))
+        .collect()
+}
+
+#[test]
+fn every_message_is_translated() {
+    // Messages that read the same as in English on purpose.
+    let same_as_english = |language, id: &str| match language {
+        Language::German => [
+            "placement-label",
+            "column-status",
+            "order-form-status",
+            "info-supported-weight",
+        ]
+        .contains(&id),
+        _ => id == "info-supported-weight",
+    };
+    let english = sources(Language::English.catalog());
+    for language in LANGUAGES {
+        if language == Language::English {
+            continue;
+        }
+        let untranslated: Vec<&str> = sources(language.catalog())
+            .into_iter()
+            .filter(|(id, text)| !same_as_english(language, id) && english.get(id) == Some(text))
+            .map(|(id, _)| id)
+            .collect();
+        assert!(
+            untranslated.is_empty(),
+            "{language} has untranslated messages {untranslated:?}"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use common::loco;
+use dv_helper::conditions::Conditions;
+use dv_helper::consist::Consist;
+use dv_helper::jobs::{JobGoal, LengthLimits, Spare, pick_jobs, spare_capacity};
+use dv_helper::locomotive::Locomotive;
+use dv_helper::order::{Order, OrderStatus};
+use dv_helper::route::legs;
+use dv_helper::station::Station;
+use dv_helper::units::{Length, Mass};
+
+fn job(weight: f32, length: f32, cars: u16, payout: u32) -> Order {
+    Order {
+        name: format!("${payout}"),
+        car_count: cars,
+        status: OrderStatus::Planned,
+        payout: Some(payout),
+        ..common::order(weight, length)
+    }
+}
+
+#[test]
+fn picks_the_best_jobs_that_fit_together() {
+    let jobs = [
+        job(60.0, 30.0, 3, 500),
+        job(50.0, 30.0, 2, 400),
+        job(50.0, 30.0, 2, 400),
+    ];
+    let spare = Spare {
+        weight: Mass::from_tonnes(100.0),
+        length: None,
+        at_stations: BTreeMap::new(),
+    };
+    assert_eq!(
+        pick_jobs(&jobs, &spare, JobGoal::Payout),
+        [1, 2],
+        "Two smaller jobs pay more than the biggest one alone"
+    );
+
+    let short = Spare {
+        length: Some(Length::from_meters(50.0)),
+        ..spare
+    };
+    assert_eq!(
+        pick_jobs(&jobs, &short, JobGoal::Cars),
+        [0],
+        "Only one job fits the track"
+    );
+}
+
+#[test]
+fn the_shorter_length_limit_applies() {
+    let limits = LengthLimits {
+        license: Some(Length::from_meters(400.0)),
+        tracks: BTreeMap::from([(Station::Harbor, Length::from_meters(250.0))]),
+    };
+    assert_eq!(
+        limits.max_at(Station::Harbor),
+        Some(Length::from_meters(250.0)),
+        "The harbor's track is shorter than the license"
+    );
+    assert_eq!(
+        limits.max_at(Station::SteelMill),
+        Some(Length::from_meters(400.0)),
+        "Elsewhere only the license applies"
+    );
+    assert_eq!(
+        LengthLimits::default().max_at(Station::Harbor),
+        None,
+        "Nothing limits the train by default"
+    );
+}
+
+#[test]
+fn fits_the_tracks_at_each_job_s_stations() {
+    let mut to_harbor = job(50.0, 60.0, 2, 500);
+    to_harbor.dropoff_station = Station::Harbor;
+    let mut to_mill = job(50.0, 60.0, 2, 400);
+    to_mill.pickup_station = Station::Harbor;
+    to_mill.dropoff_station = Station::SteelMill;
+    let mut elsewhere = job(50.0, 20.0, 1, 300);
+    elsewhere.pickup_station = Station::FoodFactory;
+    elsewhere.dropoff_station = Station::MachineFactory;
+    let jobs = [to_harbor, to_mill, elsewhere];
+
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE6)],
+        orders: Vec::new(),
+        jobs: Vec::new(),
+    };
+    let de6 = loco(Locomotive::DE6).length;
+    let limits = LengthLimits {
+        license: None,
+        tracks: BTreeMap::from([(Station::Harbor, de6 + Length::from_meters(100.0))]),
+    };
+    let spare = spare_capacity(&consist, &Conditions::default(), &limits);
+    assert_eq!(spare.length, None, "The train doesn't work the harbor yet");
+    assert_eq!(
+        pick_jobs(&jobs, &spare, JobGoal::Payout),
+        [0, 2],
+        "Only one of the jobs using the harbor's short track fits"
+    );
+    assert!(
+        !spare.fits_all(jobs.iter().take(2)),
+        "Both harbor jobs are too long for its track"
+    );
+    assert!(
+        spare.fits_all(jobs.iter().skip(1)),
+        "A harbor job and one elsewhere fit"
+    );
+}
+
+#[test]
+fn spare_weight_is_what_the_tightest_leg_leaves() {
+    let order = |weight, pickup_station, dropoff_station| Order {
+        pickup_station,
+        dropoff_station,
+        status: OrderStatus::Planned,
+        ..common::order(weight, 50.0)
+    };
+    // The climb to the forest is steeper than anything before it.
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE6)],
+        orders: vec![
+            order(600.0, Station::Harbor, Station::CityWest),
+            order(300.0, Station::CityWest, Station::ForestSouth),
+        ],
+        jobs: Vec::new(),
+    };
+    let level = Conditions {
+        grade: 0.0,
+        ..Conditions::default()
+    };
+    let tightest = legs(&consist, &level)
+        .iter()
+        .map(|leg| leg.supported - leg.weight)
+        .min_by(Mass::total_cmp)
+        .expect("The route has legs");
+    let spare = spare_capacity(&consist, &level, &LengthLimits::default());
+    assert_eq!(spare.weight, tightest, "The steepest leg limits the weight");
+    assert!(
+        spare.weight < consist.supported_weight(&level) - Mass::from_tonnes(600.0),
+        "Less than the level grade in the conditions would leave"
+    );
+}
@@ -0,0 +1,95 @@
+use dv_helper::keymap::{Action, Keymap};
+use egui::{Key, KeyboardShortcut, Modifiers};
+
+#[test]
+fn drops_bindings_of_removed_actions() {
+    let mut stored = serde_json::to_value(Keymap::default()).expect("A keymap serializes");
+    let bindings = stored
+        .get_mut("bindings")
+        .and_then(|b| b.as_array_mut())
+        .expect("The bindings are a list");
+    let mut removed = bindings
+        .first()
+        .cloned()
+        .expect("There are default bindings");
+    *removed
+        .get_mut("action")
+        .expect("A binding names its action") = "ImportSave".into();
+    bindings.push(removed);
+
+    let keymap: Keymap = serde_json::from_value(stored).expect("The keymap still loads");
+    assert_eq!(
+        keymap,
+        Keymap::default(),
+        "Only the binding of the removed action is dropped"
+    );
+}
+
+// Starts a frame in which the shortcut is pressed.
+fn press(shortcut: KeyboardShortcut) -> egui::Context {
+    let ctx = egui::Context::default();
+    ctx.begin_pass(egui::RawInput {
+        events: vec![egui::Event::Key {
+            key: shortcut.logical_key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: shortcut.modifiers,
+        }],
+        modifiers: shortcut.modifiers,
+        ..Default::default()
+    });
+    ctx
+}
+
+#[test]
+fn rebinding_takes_the_shortcut_from_other_actions() {
+    let mut keymap = Keymap::default();
+    let undo = keymap.shortcut(Action::Undo).expect("Undo has a shortcut");
+    let old_trip = keymap.shortcut(Action::Trip).expect("Trip has a shortcut");
+
+    keymap.bind(Action::Trip, undo);
+    assert_eq!(
+        keymap.shortcut(Action::Trip),
+        Some(undo),
+        "Trip has the new shortcut"
+    );
+    assert_eq!(keymap.shortcut(Action::Undo), None, "Undo lost it");
+    assert_eq!(
+        keymap.consume(&press(old_trip)),
+        None,
+        "Trip's old shortcut is free"
+    );
+    assert_eq!(
+        keymap.consume(&press(undo)),
+        Some(Action::Trip),
+        "The shortcut runs trip now"
+    );
+}
+
+#[test]
+fn the_shortcut_with_more_modifiers_wins() {
+    let keymap = Keymap::default();
+    let redo = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z);
+    assert_eq!(
+        keymap.shortcut(Action::Redo),
+        Some(redo),
+        "Redo is bound to shift and the undo key"
+    );
+    // Undo's shortcut matches too, since egui lets extra modifiers through.
+    assert_eq!(
+        keymap.consume(&press(redo)),
+        Some(Action::Redo),
+        "Redo is more specific than undo"
+    );
+    assert_eq!(
+        keymap.consume(&press(KeyboardShortcut::new(Modifiers::COMMAND, Key::Z))),
+        Some(Action::Undo),
+        "Without shift it's undo"
+    );
+    assert_eq!(
+        keymap.consume(&press(KeyboardShortcut::new(Modifiers::NONE, Key::Q))),
+        None,
+        "Unbound keys do nothing"
+    );
+}
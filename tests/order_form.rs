@@ -0,0 +1,110 @@
+use dv_helper::i18n::{I18n, Language};
+use dv_helper::order::{OrderModal, OrderModalMode, is_valid_track};
+use dv_helper::station::Station;
+
+// A form that is valid as it stands, for tests to break one field of.
+fn form() -> OrderModal {
+    let mut form = OrderModal::new(OrderModalMode::New);
+    form.order_name = "Ore".to_owned();
+    form.weight = "312.5".to_owned();
+    form.length = "74.5".to_owned();
+    form.car_count = "5".to_owned();
+    form.pickup = Station::SteelMill;
+    form.pickup_track = "B7L".to_owned();
+    form.dropoff = Station::Harbor;
+    form.dropoff_track = "C2I".to_owned();
+    form
+}
+
+#[test]
+fn checks_track_names() {
+    for track in ["B7L", "B12C", "A1O"] {
+        assert!(is_valid_track(track), "{track} is a track");
+    }
+    for track in ["", "A1", "B", "7L", "B123L", "BB7L", "b7l", " B7L ", "B7L1"] {
+        assert!(!is_valid_track(track), "{track:?} is not a track");
+    }
+}
+
+#[test]
+fn accepts_a_filled_in_form() {
+    let i18n = I18n::new(Language::English);
+    assert!(form().validate(&i18n).is_valid(), "The base form is valid");
+
+    // Tracks are typed loosely and written the way the game writes them.
+    let mut loose = form();
+    loose.pickup_track = " b7l ".to_owned();
+    loose.dropoff_track = "c2i".to_owned();
+    assert!(
+        loose.validate(&i18n).is_valid(),
+        "Case and spaces don't matter"
+    );
+}
+
+#[test]
+fn flags_each_bad_field() {
+    let i18n = I18n::new(Language::English);
+
+    let mut empty = form();
+    empty.order_name = "  ".to_owned();
+    empty.weight = String::new();
+    empty.pickup_track = String::new();
+    let errors = empty.validate(&i18n);
+    assert!(errors.name.is_some(), "The name is required");
+    assert!(errors.weight.is_some(), "The weight is required");
+    assert!(
+        errors.pickup_track.is_some(),
+        "The pickup track is required"
+    );
+    assert!(!errors.is_valid(), "An empty form is invalid");
+
+    let mut bad = form();
+    bad.weight = "heavy".to_owned();
+    bad.length = "0".to_owned();
+    bad.car_count = "1000".to_owned();
+    bad.speed_limit = "500".to_owned();
+    bad.payout = "-5".to_owned();
+    bad.dropoff_track = "A1".to_owned();
+    let errors = bad.validate(&i18n);
+    assert!(errors.weight.is_some(), "The weight is not a number");
+    assert!(errors.length.is_some(), "The length is out of range");
+    assert!(errors.car_count.is_some(), "Too many cars");
+    assert!(errors.speed_limit.is_some(), "The speed limit is too high");
+    assert!(errors.payout.is_some(), "The payout is negative");
+    assert!(errors.dropoff_track.is_some(), "A1 has no track type");
+    assert!(errors.name.is_none(), "The name is fine");
+}
+
+#[test]
+fn needs_the_cars_to_go_somewhere() {
+    let i18n = I18n::new(Language::English);
+
+    let mut same = form();
+    same.dropoff = same.pickup;
+    same.dropoff_track = " b7l".to_owned();
+    let errors = same.validate(&i18n);
+    assert!(errors.dropoff_track.is_some(), "Same station and track");
+
+    let mut shunting = form();
+    shunting.dropoff = shunting.pickup;
+    shunting.dropoff_track = "C1S".to_owned();
+    assert!(
+        shunting.validate(&i18n).is_valid(),
+        "Another track at the same station is a shunting job"
+    );
+
+    let mut empty = form();
+    empty.dropoff = empty.pickup;
+    empty.dropoff_track = String::new();
+    assert!(
+        !empty.validate(&i18n).is_valid(),
+        "An empty dropoff track is never valid"
+    );
+
+    let mut other = form();
+    other.dropoff_track = "B7L".to_owned();
+    assert!(
+        other.validate(&i18n).is_valid(),
+        "The same track name at another station is another place"
+    );
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::order;
+use dv_helper::order::{Order, OrderStatus};
+use dv_helper::order_table::{OrderFilter, SortColumn};
+use dv_helper::station::Station;
+
+fn named(name: &str, weight: f32, pickup: Station, dropoff: Station) -> Order {
+    Order {
+        name: name.to_owned(),
+        pickup_station: pickup,
+        dropoff_station: dropoff,
+        ..order(weight, 30.0)
+    }
+}
+
+fn orders() -> Vec<Order> {
+    vec![
+        named("Ore", 300.0, Station::SteelMill, Station::Harbor),
+        named("Oil", 100.0, Station::OilWellNorth, Station::Harbor),
+        named("Steel", 300.0, Station::SteelMill, Station::MachineFactory),
+        named("Bread", 100.0, Station::FoodFactory, Station::SteelMill),
+    ]
+}
+
+fn names(orders: &[Order], visible: &[usize]) -> Vec<String> {
+    visible
+        .iter()
+        .filter_map(|ix| orders.get(*ix))
+        .map(|o| o.name.clone())
+        .collect()
+}
+
+#[test]
+fn filters_by_station() {
+    let orders = orders();
+    let at_mill = OrderFilter {
+        station: Some(Station::SteelMill),
+        ..OrderFilter::default()
+    };
+    assert_eq!(
+        names(&orders, &at_mill.visible(&orders)),
+        ["Ore", "Steel", "Bread"],
+        "Orders picked up or dropped off at the station"
+    );
+
+    let to_harbor = OrderFilter {
+        pickup: Some(Station::SteelMill),
+        dropoff: Some(Station::Harbor),
+        ..OrderFilter::default()
+    };
+    assert_eq!(
+        names(&orders, &to_harbor.visible(&orders)),
+        ["Ore"],
+        "Pickup and dropoff filters both apply"
+    );
+
+    let search = OrderFilter {
+        search: " ST ".to_owned(),
+        ..OrderFilter::default()
+    };
+    assert_eq!(
+        names(&orders, &search.visible(&orders)),
+        ["Steel"],
+        "The search ignores case and surrounding spaces"
+    );
+    assert!(search.is_filtering(), "A search filters the table");
+    assert!(
+        !OrderFilter::default().is_filtering(),
+        "Nothing is hidden by default"
+    );
+}
+
+#[test]
+fn filters_by_status() {
+    let mut orders = orders();
+    if let Some(oil) = orders.get_mut(1) {
+        oil.status = OrderStatus::Delivered;
+    }
+    if let Some(bread) = orders.get_mut(3) {
+        bread.status = OrderStatus::Planned;
+    }
+    let filter = OrderFilter::default();
+    assert_eq!(
+        names(&orders, &filter.open_orders(&orders)),
+        ["Ore", "Steel", "Bread"],
+        "Delivered orders are listed apart"
+    );
+
+    let by_status = OrderFilter {
+        sort: Some((SortColumn::Status, true)),
+        station: Some(Station::SteelMill),
+        ..OrderFilter::default()
+    };
+    assert_eq!(
+        names(&orders, &by_status.open_orders(&orders)),
+        ["Bread", "Ore", "Steel"],
+        "Planned orders sort before picked up ones, within the station filter"
+    );
+}
+
+#[test]
+fn sorts_stably() {
+    let orders = orders();
+    let by_weight = |ascending| OrderFilter {
+        sort: Some((SortColumn::Weight, ascending)),
+        ..OrderFilter::default()
+    };
+    assert_eq!(
+        names(&orders, &by_weight(true).visible(&orders)),
+        ["Oil", "Bread", "Ore", "Steel"],
+        "Orders of the same weight keep the consist's order"
+    );
+    assert_eq!(
+        names(&orders, &by_weight(false).visible(&orders)),
+        ["Ore", "Steel", "Oil", "Bread"],
+        "Also when sorting descending"
+    );
+    assert_eq!(
+        OrderFilter::default().visible(&orders),
+        [0, 1, 2, 3],
+        "Unsorted, the table follows the consist"
+    );
+}
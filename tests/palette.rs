@@ -0,0 +1,45 @@
+use dv_helper::palette::fuzzy_score;
+
+#[test]
+fn needs_every_character_in_order() {
+    assert!(
+        fuzzy_score("trip", "Show/hide trip").is_some(),
+        "A plain match"
+    );
+    assert!(
+        fuzzy_score("TRIP", "show/hide trip").is_some(),
+        "Case doesn't matter"
+    );
+    assert!(
+        fuzzy_score("sug pow", "Suggest power").is_some(),
+        "Spaces in the query are ignored"
+    );
+    assert_eq!(fuzzy_score("pirt", "Show/hide trip"), None, "Out of order");
+    assert_eq!(
+        fuzzy_score("tripx", "Show/hide trip"),
+        None,
+        "Missing a character"
+    );
+    assert_eq!(
+        fuzzy_score("", "Undo"),
+        Some(0),
+        "An empty query matches anything"
+    );
+}
+
+#[test]
+fn ranks_closer_matches_higher() {
+    let score = |query, text| fuzzy_score(query, text).expect("The query matches");
+    assert!(
+        score("undo", "Undo") > score("undo", "Unbind orders"),
+        "Consecutive characters beat scattered ones"
+    );
+    assert!(
+        score("sp", "Suggest power") > score("sp", "Show/hide station map"),
+        "Matches at the start of words beat matches inside them"
+    );
+    assert!(
+        score("map", "Show/hide station map") > score("map", "Keymap"),
+        "A whole word beats the end of another"
+    );
+}
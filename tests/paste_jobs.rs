@@ -0,0 +1,206 @@
+use dv_helper::order::Order;
+use dv_helper::paste_jobs::parse_job_text;
+use dv_helper::station::Station;
+use dv_helper::units::{Length, Mass, MassUnit, Units};
+
+fn summary(order: &Order) -> (&str, u16, Mass, Length) {
+    (
+        order.job_id.as_str(),
+        order.car_count,
+        order.weight,
+        order.length,
+    )
+}
+
+fn route(order: &Order) -> (Station, &str, Station, &str) {
+    (
+        order.pickup_station,
+        order.pickup_track.as_str(),
+        order.dropoff_station,
+        order.dropoff_track.as_str(),
+    )
+}
+
+#[test]
+fn parses_one_job_per_line() {
+    let orders = parse_job_text(
+        "SM-FH-12  5 cars  312.5 t  74.5 m  SM-B7L -> HB-C2I  $4250\n\
+         OWN-LH-03 | 2 cars | 120t | 30m | OWN-A1O → OR-B4I",
+        Units::default(),
+    );
+    assert_eq!(orders.len(), 2, "One order per line");
+    let [ore, oil] = orders.as_slice() else {
+        panic!("Expected two orders, got {}", orders.len());
+    };
+    assert_eq!(
+        summary(ore),
+        (
+            "SM-FH-12",
+            5,
+            Mass::from_tonnes(312.5),
+            Length::from_meters(74.5)
+        ),
+        "Quantities are read by their units"
+    );
+    assert_eq!(
+        route(ore),
+        (Station::SteelMill, "B7L", Station::Harbor, "C2I"),
+        "Origin, then destination"
+    );
+    assert_eq!(ore.payout, Some(4250), "A dollar amount is the payout");
+    assert_eq!(ore.name, "SM-FH-12", "Orders are named after the job");
+    assert_eq!(
+        summary(oil),
+        (
+            "OWN-LH-03",
+            2,
+            Mass::from_tonnes(120.0),
+            Length::from_meters(30.0)
+        ),
+        "Units can be attached to the number"
+    );
+    assert_eq!(oil.payout, None, "No payout given");
+}
+
+#[test]
+fn parses_blocks_with_labels() {
+    let orders = parse_job_text(
+        "SM-FH-12\n\
+         Cars: 5\n\
+         Weight: 312.5\n\
+         Length: 74.5\n\
+         From: SM-B7L\n\
+         To: HB-C2I\n\
+         \n\
+         FF-SU-07\n\
+         Weight: 40\n",
+        Units::default(),
+    );
+    assert_eq!(orders.len(), 2, "A block per job");
+    let [ore, bread] = orders.as_slice() else {
+        panic!("Expected two orders, got {}", orders.len());
+    };
+    assert_eq!(
+        summary(ore),
+        (
+            "SM-FH-12",
+            5,
+            Mass::from_tonnes(312.5),
+            Length::from_meters(74.5)
+        ),
+        "Quantities are read by their labels"
+    );
+    assert_eq!(
+        route(ore),
+        (Station::SteelMill, "B7L", Station::Harbor, "C2I"),
+        "Tracks are read across lines"
+    );
+    assert_eq!(
+        bread.weight,
+        Mass::from_tonnes(40.0),
+        "The second block doesn't take the first one's values"
+    );
+}
+
+#[test]
+fn leaves_missing_fields_empty() {
+    let orders = parse_job_text("Some text first\nFF-SU-07 12 m", Units::default());
+    let [bread] = orders.as_slice() else {
+        panic!("Expected one order, got {}", orders.len());
+    };
+    assert_eq!(
+        summary(bread),
+        ("FF-SU-07", 0, Mass::ZERO, Length::from_meters(12.0)),
+        "Missing quantities are zero"
+    );
+    assert_eq!(
+        route(bread),
+        (Station::FoodFactory, "", Station::FoodFactory, ""),
+        "Without tracks the job stays at its origin station"
+    );
+    assert!(
+        parse_job_text("No jobs in here, just 5 cars", Units::default()).is_empty(),
+        "Text before any job ID is ignored"
+    );
+}
+
+#[test]
+fn reads_thousands_separators() {
+    let orders = parse_job_text(
+        "SM-FH-12, 12 cars, 1,250 t, 180 m, $4,250, SM-B7L -> HB-C2I",
+        Units::default(),
+    );
+    let [ore] = orders.as_slice() else {
+        panic!("Expected one order, got {}", orders.len());
+    };
+    assert_eq!(ore.payout, Some(4250), "$4,250 is one amount");
+    assert_eq!(
+        summary(ore),
+        (
+            "SM-FH-12",
+            12,
+            Mass::from_tonnes(1250.0),
+            Length::from_meters(180.0)
+        ),
+        "1,250 t is one weight, and commas still separate the fields"
+    );
+}
+
+#[test]
+fn reads_tons_in_the_preferred_units() {
+    let short = Units {
+        mass: MassUnit::ShortTons,
+        ..Units::default()
+    };
+    let weight = |text: &str, units: Units| {
+        parse_job_text(text, units)
+            .first()
+            .map(|order| order.weight)
+            .expect("The text has a job")
+    };
+    assert_eq!(
+        weight("SM-FH-12 100 tons", short),
+        Mass::from_unit(100.0, MassUnit::ShortTons),
+        "Tons are short tons when those are preferred"
+    );
+    assert_eq!(
+        weight("SM-FH-12 100 tons", Units::default()),
+        Mass::from_tonnes(100.0),
+        "Tons are tonnes otherwise"
+    );
+    assert_eq!(
+        weight("SM-FH-12 100 t", short),
+        Mass::from_tonnes(100.0),
+        "A t is always a tonne"
+    );
+    assert_eq!(
+        weight("SM-FH-12 Weight: 100", short),
+        Mass::from_unit(100.0, MassUnit::ShortTons),
+        "A weight without a unit is in the preferred unit"
+    );
+}
+
+#[test]
+fn needs_a_job_type() {
+    assert!(
+        parse_job_text("SM--12 5 cars", Units::default()).is_empty(),
+        "SM--12 has no job type, so it isn't a job ID"
+    );
+}
+
+#[test]
+fn keeps_numbers_in_a_list_apart() {
+    let orders = parse_job_text(
+        "SM-FH-12 Cars: 5,312,Weight: 1,250.5 t,$4,250",
+        Units::default(),
+    );
+    let [ore] = orders.as_slice() else {
+        panic!("Expected one order, got {}", orders.len());
+    };
+    assert_eq!(
+        summary(ore),
+        ("SM-FH-12", 5, Mass::from_tonnes(1250.5), Length::ZERO),
+        "Without a unit or $ the comma separates 5 and 312"
+    );
+    assert_eq!(ore.payout, Some(4250), "$4,250 is still one amount");
+}
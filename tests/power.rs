@@ -0,0 +1,167 @@
+mod common;
+
+use common::loco;
+use dv_helper::conditions::Conditions;
+use dv_helper::consist::Consist;
+use dv_helper::locomotive::Locomotive;
+use dv_helper::order::{Order, OrderStatus};
+use dv_helper::power::{Demand, PowerGoal, Roster, demands, suggest_power};
+use dv_helper::route::legs;
+use dv_helper::station::Station;
+use dv_helper::units::Mass;
+
+fn on_grade(tonnes: f32, grade: f32) -> [Demand; 1] {
+    [Demand {
+        load: Mass::from_tonnes(tonnes),
+        grade,
+    }]
+}
+
+#[test]
+fn suggests_the_fewest_locomotives_first() {
+    let options = suggest_power(
+        &on_grade(1000.0, 2.0),
+        &Conditions::default(),
+        &Roster::default(),
+        PowerGoal::Fewest,
+    );
+    let best = options.first().expect("Something can pull 1000 t");
+    assert_eq!(best.locomotives, [(Locomotive::DE6, 1)]);
+    assert!(
+        best.margin() >= Mass::ZERO,
+        "Every option can pull the load"
+    );
+    assert!(options.len() > 1, "More than one option is shown");
+}
+
+#[test]
+fn respects_licenses_and_owned_units() {
+    let mut roster = Roster::default();
+    roster.set_licensed(Locomotive::DE6, false);
+    roster.set_owned(Locomotive::DH4, Some(1));
+    let options = suggest_power(
+        &on_grade(1000.0, 2.0),
+        &Conditions::default(),
+        &roster,
+        PowerGoal::Cheapest,
+    );
+    assert!(!options.is_empty());
+    for option in &options {
+        assert!(
+            option
+                .locomotives
+                .iter()
+                .all(|(loco, count)| *loco != Locomotive::DE6
+                    && (*loco != Locomotive::DH4 || *count <= 1)),
+            "Only licensed and owned units: {:?}",
+            option.locomotives
+        );
+    }
+}
+
+#[test]
+fn counts_the_units_that_stay_on() {
+    let caboose = loco(Locomotive::Caboose);
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2), caboose.clone()],
+        orders: vec![common::order(500.0, 50.0)],
+        jobs: Vec::new(),
+    };
+    let conditions = Conditions::default();
+    let demands = demands(&consist, &conditions);
+    assert_eq!(
+        demands.first().map(|d| d.load),
+        Some(Mass::from_tonnes(500.0) + caboose.weight),
+        "The caboose stays, the DE2 is replaced"
+    );
+
+    let options = suggest_power(&demands, &conditions, &Roster::default(), PowerGoal::Fewest);
+    let best = options.first().expect("Something can pull 500 t");
+    let locos: Mass = best.infos().iter().map(|l| l.weight).sum();
+    assert_eq!(
+        best.weight,
+        Mass::from_tonnes(500.0) + caboose.weight + locos,
+        "The caboose is part of what is pulled"
+    );
+}
+
+#[test]
+fn checks_every_leg_at_its_own_grade() {
+    // Flat where the train is now, but the way to the Forest South climbs 3%.
+    let conditions = Conditions {
+        grade: 0.0,
+        ..Conditions::default()
+    };
+    let consist = Consist {
+        locomotives: Vec::new(),
+        orders: vec![Order {
+            pickup_station: Station::CityWest,
+            dropoff_station: Station::ForestSouth,
+            status: OrderStatus::Planned,
+            ..common::order(600.0, 50.0)
+        }],
+        jobs: Vec::new(),
+    };
+    let demands = demands(&consist, &conditions);
+    assert!(
+        demands.iter().any(|d| d.grade >= 3.0),
+        "The climb is one of the demands"
+    );
+
+    let options = suggest_power(&demands, &conditions, &Roster::default(), PowerGoal::Fewest);
+    assert!(!options.is_empty(), "Something can pull 600 t up 3%");
+    for option in &options {
+        let powered = Consist {
+            locomotives: option.infos(),
+            ..consist.clone()
+        };
+        assert!(
+            legs(&powered, &conditions).iter().all(|leg| !leg.stalls()),
+            "{:?} stalls on the way",
+            option.locomotives
+        );
+    }
+}
+
+#[test]
+fn lightest_compares_the_locomotives_alone() {
+    // With one DE2 and one S060 to choose from, a long flat leg and a short
+    // steep one are tightest for different options. The lightest choice is
+    // the one whose locomotives weigh least, not the one whose train is
+    // lightest on its own tightest leg.
+    let mut roster = Roster::default();
+    for candidate in dv_helper::locomotive::LOCO_LIST {
+        let usable = matches!(candidate, Locomotive::DE2 | Locomotive::S060);
+        roster.set_licensed(candidate, usable);
+        if usable {
+            roster.set_owned(candidate, Some(1));
+        }
+    }
+    let legs = [
+        Demand {
+            load: Mass::from_tonnes(1100.0),
+            grade: 0.0,
+        },
+        Demand {
+            load: Mass::from_tonnes(50.0),
+            grade: 3.0,
+        },
+    ];
+    let options = suggest_power(&legs, &Conditions::default(), &roster, PowerGoal::Lightest);
+    let chosen: Vec<_> = options.iter().map(|o| o.locomotives.clone()).collect();
+    assert_eq!(
+        chosen,
+        [
+            vec![(Locomotive::DE2, 1)],
+            vec![(Locomotive::S060, 1)],
+            vec![(Locomotive::DE2, 1), (Locomotive::S060, 1)]
+        ],
+        "Ordered by the weight of the locomotives"
+    );
+    let best = options.first().expect("The DE2 can do it");
+    assert_eq!(
+        best.loco_weight,
+        loco(Locomotive::DE2).weight,
+        "Only the locomotives are weighed"
+    );
+}
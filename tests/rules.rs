@@ -0,0 +1,132 @@
+mod common;
+
+use common::loco;
+use dv_helper::consist::Consist;
+use dv_helper::hazmat::{HazmatClass, safe_order};
+use dv_helper::locomotive::{Locomotive, Placement};
+use dv_helper::order::Order;
+use dv_helper::rules::{Rule, RuleConfig, RuleSetting, Severity, check};
+
+fn order(name: &str, cargo: &str, weight: f32) -> Order {
+    Order {
+        name: name.to_owned(),
+        cargo: cargo.to_owned(),
+        ..common::order(weight, 30.0)
+    }
+}
+
+#[test]
+fn checks_each_rule() {
+    let mut caboose = loco(Locomotive::Caboose);
+    caboose.placement = Placement::Head;
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE2), loco(Locomotive::DE6Slug), caboose],
+        orders: vec![order("Ammonia", "Ammonia", 100.0), order("Empty", "", 0.0)],
+        jobs: Vec::new(),
+    };
+    let broken: Vec<(Rule, Vec<usize>)> = check(&consist, &RuleConfig::default())
+        .into_iter()
+        .map(|v| (v.rule, v.orders))
+        .collect();
+    assert_eq!(
+        broken,
+        [
+            (Rule::HazmatNextToLocomotive, vec![0]),
+            (Rule::SlugNextToDe6, Vec::new()),
+            (Rule::CabooseLast, Vec::new()),
+            (Rule::ZeroWeightOrder, vec![1]),
+        ],
+        "Errors first"
+    );
+}
+
+#[test]
+fn rules_can_be_turned_off_or_downgraded() {
+    let consist = Consist {
+        locomotives: vec![loco(Locomotive::DE6)],
+        orders: vec![order("Chlorine", "Chlorine", 100.0)],
+        jobs: Vec::new(),
+    };
+    let mut config = RuleConfig::default();
+    let violations = check(&consist, &config);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations.first().map(|v| v.severity),
+        Some(Severity::Error)
+    );
+
+    config.set(
+        Rule::HazmatNextToLocomotive,
+        RuleSetting {
+            enabled: true,
+            severity: Severity::Info,
+        },
+    );
+    assert_eq!(
+        check(&consist, &config).first().map(|v| v.severity),
+        Some(Severity::Info)
+    );
+    config.set(
+        Rule::HazmatNextToLocomotive,
+        RuleSetting {
+            enabled: false,
+            severity: Severity::Info,
+        },
+    );
+    assert!(check(&consist, &config).is_empty(), "Rule turned off");
+}
+
+#[test]
+fn classifies_cargo() {
+    assert_eq!(
+        HazmatClass::from_cargo("CrudeOil, Methane"),
+        Some(HazmatClass::Gas)
+    );
+    assert_eq!(
+        HazmatClass::from_cargo("Crude oil"),
+        Some(HazmatClass::Flammable)
+    );
+    assert_eq!(HazmatClass::from_cargo("Logs"), None);
+}
+
+#[test]
+fn suggests_a_safe_order() {
+    let mut consist = Consist {
+        locomotives: vec![loco(Locomotive::DE6)],
+        orders: vec![
+            order("Gas", "Methane", 100.0),
+            order("Fuel", "Diesel", 100.0),
+            order("Logs", "Logs", 100.0),
+            order("Steel", "SteelRolls", 100.0),
+        ],
+        jobs: Vec::new(),
+    };
+    let broken: Vec<Rule> = check(&consist, &RuleConfig::default())
+        .into_iter()
+        .map(|v| v.rule)
+        .collect();
+    assert_eq!(
+        broken,
+        [Rule::HazmatNextToLocomotive, Rule::HazmatSeparation]
+    );
+
+    let safe = safe_order(&consist).expect("A safe order exists");
+    consist.orders = safe
+        .iter()
+        .filter_map(|ix| consist.orders.get(*ix).cloned())
+        .collect();
+    assert!(check(&consist, &RuleConfig::default()).is_empty());
+
+    let mut boxed_in = Consist {
+        locomotives: vec![loco(Locomotive::DE6), loco(Locomotive::Caboose)],
+        orders: vec![order("Gas", "Methane", 100.0)],
+        jobs: Vec::new(),
+    };
+    assert_eq!(
+        safe_order(&boxed_in),
+        None,
+        "Nowhere to put a lone hazmat cut"
+    );
+    boxed_in.orders.push(order("Logs", "Logs", 100.0));
+    assert_eq!(safe_order(&boxed_in), None, "Still next to a locomotive");
+}
@@ -0,0 +1,69 @@
+use dv_helper::car::CarType;
+use dv_helper::save::{SaveError, parse_save};
+use dv_helper::station::Station;
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read(&path).unwrap_or_else(|err| panic!("Failed to read {path}: {err}"))
+}
+
+#[test]
+fn imports_taken_jobs() {
+    let orders = parse_save(&fixture("savegame_v3.json")).expect("Fixture should parse");
+    let ids: Vec<&str> = orders.iter().map(|o| o.job_id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["SM-FH-12", "OWN-LH-03"],
+        "Only taken jobs are imported"
+    );
+
+    let ore = orders.first().expect("At least one order");
+    assert_eq!(ore.car_count, 3, "Car count");
+    assert_eq!(ore.car_type, Some(CarType::Hopper), "Car type");
+    assert_eq!(ore.cargo, "IronOre", "Cargo");
+    assert_eq!(ore.pickup_station, Station::SteelMill, "Pickup station");
+    assert_eq!(ore.pickup_track, "B7L", "Pickup track");
+    assert_eq!(ore.dropoff_station, Station::Harbor, "Dropoff station");
+    assert_eq!(ore.dropoff_track, "C2I", "Dropoff track");
+
+    let hopper = CarType::Hopper.info();
+    let expected_weight = hopper.empty_weight * 3.0 + hopper.cargo_capacity * 2.5;
+    assert!(
+        (ore.weight - expected_weight).tonnes().abs() < 0.01,
+        "Weight counts partially loaded cars"
+    );
+    assert!(
+        (ore.length - hopper.length * 3.0).meters().abs() < 0.01,
+        "Length is the sum of the cars"
+    );
+}
+
+#[test]
+fn rejects_encrypted_saves() {
+    let result = parse_save(&fixture("savegame_encrypted.sav"));
+    assert!(matches!(result, Err(SaveError::NotJson)), "Encrypted save");
+}
+
+#[test]
+fn rejects_unsupported_versions() {
+    let result = parse_save(&fixture("savegame_v9.json"));
+    assert!(
+        matches!(result, Err(SaveError::UnsupportedVersion(9))),
+        "Unsupported save version"
+    );
+}
+
+#[test]
+fn rejects_unknown_tracks() {
+    let result = parse_save(&fixture("savegame_unknown_track.json"));
+    assert!(
+        matches!(result, Err(SaveError::UnknownTrack { track, .. }) if track == "XX-A1L"),
+        "Unknown track"
+    );
+}
+
+#[test]
+fn rejects_json_that_is_not_a_save() {
+    let result = parse_save(br#"{ "settings": { "volume": 0.5 } }"#);
+    assert!(matches!(result, Err(SaveError::Malformed(_))), "Not a save");
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::order;
+use dv_helper::consist::Consist;
+use dv_helper::history::{Command, History};
+use dv_helper::order::{Order, OrderStatus};
+use dv_helper::route::{after_stop, planned_route};
+use dv_helper::station::Station;
+use dv_helper::trip::status_changes;
+
+fn named(name: &str, pickup: Station, dropoff: Station, status: OrderStatus) -> Order {
+    Order {
+        name: name.to_owned(),
+        pickup_station: pickup,
+        dropoff_station: dropoff,
+        status,
+        ..order(100.0, 30.0)
+    }
+}
+
+fn statuses(consist: &Consist) -> Vec<OrderStatus> {
+    consist.orders.iter().map(|o| o.status).collect()
+}
+
+#[test]
+fn works_each_stop_of_a_trip() {
+    use OrderStatus::{Delivered, PickedUp, Planned};
+
+    // Ore is picked up and delivered on this trip, bread is picked up where
+    // the ore is set out, and oil is already on the train.
+    let mut consist = Consist {
+        locomotives: Vec::new(),
+        orders: vec![
+            named("Ore", Station::SteelMill, Station::Harbor, Planned),
+            named("Bread", Station::Harbor, Station::FoodFactory, Planned),
+            named(
+                "Oil",
+                Station::OilWellNorth,
+                Station::MachineFactory,
+                PickedUp,
+            ),
+        ],
+        jobs: Vec::new(),
+    };
+    let route = planned_route(&consist.orders);
+    assert_eq!(
+        route,
+        [
+            Station::SteelMill,
+            Station::Harbor,
+            Station::FoodFactory,
+            Station::MachineFactory
+        ],
+        "The harbor is visited once, to set out and pick up"
+    );
+
+    let expected = [
+        [PickedUp, Planned, PickedUp],
+        [Delivered, PickedUp, PickedUp],
+        [Delivered, Delivered, PickedUp],
+        [Delivered, Delivered, Delivered],
+    ];
+    let mut history = History::default();
+    for (station, expected) in route.into_iter().zip(expected) {
+        let predicted = after_stop(&consist, station);
+        history.execute(
+            Command::Batch(status_changes(&consist.orders, station)),
+            &mut consist,
+        );
+        assert_eq!(statuses(&consist), expected, "After working {station}");
+        assert!(
+            consist == predicted,
+            "Working {station} matches what the route predicts"
+        );
+    }
+
+    assert!(
+        status_changes(&consist.orders, Station::Harbor).is_empty(),
+        "Nothing is left to do once everything is delivered"
+    );
+    assert!(history.undo(&mut consist), "The last stop can be undone");
+    assert_eq!(
+        statuses(&consist),
+        [Delivered, Delivered, PickedUp],
+        "Undoing a stop puts its orders back on the train"
+    );
+}
@@ -0,0 +1,41 @@
+use dv_helper::units::{Length, LengthUnit, Mass, MassUnit, Units};
+
+#[test]
+fn converts_between_units() {
+    let mass = Mass::from_unit(100.0, MassUnit::ShortTons);
+    assert!(
+        (mass.tonnes() - 90.718).abs() < 0.01,
+        "100 short tons in tonnes"
+    );
+    assert!(
+        (mass.in_unit(MassUnit::ShortTons) - 100.0).abs() < 0.01,
+        "Round trip through tonnes"
+    );
+    let length = Length::from_unit(100.0, LengthUnit::Feet);
+    assert!((length.meters() - 30.48).abs() < 0.01, "100 feet in meters");
+    assert!(
+        (Length::from_millimeters(7600.0).meters() - 7.6).abs() < 0.001,
+        "Millimeters to meters"
+    );
+}
+
+#[test]
+fn formats_in_the_preferred_units() {
+    let metric = Units::default();
+    assert_eq!(metric.mass(Mass::from_tonnes(312.5)), "312.50 T", "Tonnes");
+    assert_eq!(
+        metric.length(Length::from_meters(74.5)),
+        "74.50 m",
+        "Meters"
+    );
+    let us = Units {
+        mass: MassUnit::ShortTons,
+        length: LengthUnit::Feet,
+    };
+    assert_eq!(
+        us.mass(Mass::from_tonnes(0.907_184_7)),
+        "1.00 tn",
+        "Short tons"
+    );
+    assert_eq!(us.length(Length::from_meters(3.048)), "10.00 ft", "Feet");
+}